@@ -9,6 +9,8 @@ use std::{
 
 #[impl_enum::with_methods {
     fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()>
+    /// Writes a buffer into the writer,
+    /// returning how many bytes were written.
     pub fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>
 }]
 pub enum Writer {
@@ -1,108 +1,595 @@
-use heck::ToSnakeCase;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::ToTokens;
 use syn::{
+    ext::IdentExt,
     parse::{Parse, ParseStream},
-    Error, ItemEnum, Path, Token,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token, Error, GenericParam, ItemEnum, Path, Token, Type, Visibility,
 };
 
 pub fn as_dyn_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
-    let paths = syn::parse_macro_input!(arg as Paths);
+    let args = match super::parse_args::<Args>(
+        arg.into(),
+        "Expected a comma-separated list of trait paths",
+    ) {
+        Ok(args) => args,
+        Err(err) => return err.into_compile_error().into(),
+    };
     let input_enum = syn::parse_macro_input!(input as ItemEnum);
 
     let mut enum_impls = vec![];
-    for path in paths.0 {
-        match make_impl(&path, &input_enum) {
+    for target in &args.targets {
+        match make_impl(target, &args, &input_enum) {
             Ok(enum_impl) => enum_impls.push(enum_impl),
             Err(err) => return err.into_compile_error().into(),
         };
     }
 
+    // return the enum without helper attributes and impls
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
     TokenStream::from(quote::quote! {
         #input_enum
         #(#enum_impls)*
     })
 }
 
-struct Paths(Vec<Path>);
+struct Args {
+    /// The visibility of the generated methods.
+    vis: Visibility,
+    /// Use the `alloc` crate instead of `std` for the allocating types.
+    alloc: bool,
+    /// Generate a method that converts the enum into an `Arc` trait object.
+    arc: bool,
+    /// Bound the generic parameters used as delegate fields by each trait in the generated impl.
+    bound: bool,
+    /// The delegate fields are collections of values that implement the traits.
+    iter: bool,
+    /// Generate a method that converts the enum into an `Rc` trait object.
+    rc: bool,
+    /// The delegate fields are read-write locks of values that implement the traits.
+    lock: bool,
+    /// Generate a function that converts a slice of the enum into trait objects.
+    slice: bool,
+    targets: Vec<Target>,
+}
+
+struct Target {
+    /// The trait of the trait objects, along with any additional bounds such as `Send`.
+    bounds: Punctuated<Path, Token![+]>,
+    /// The name used in the method names instead of `dyn_{trait}`.
+    alias: Option<Ident>,
+}
 
-impl Parse for Paths {
+impl Parse for Target {
     fn parse(input: ParseStream) -> Result<Self, Error> {
-        // loop over the input and parse paths
-        let paths = input
-            .parse_terminated(Path::parse, Token![,])?
+        let bounds = Punctuated::parse_separated_nonempty(input)?;
+        let alias = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Target { bounds, alias })
+    }
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        // the visibility is separated from the list by a semicolon
+        let vis = if input.peek(Token![pub]) {
+            let vis = input.parse()?;
+            input.parse::<Token![;]>()?;
+            vis
+        } else {
+            Visibility::Inherited
+        };
+
+        // the flags are given in a separate group so that they can't be confused with trait paths
+        let mut alloc = false;
+        let mut arc = false;
+        let mut bound = false;
+        let mut iter = false;
+        let mut lock = false;
+        let mut rc = false;
+        let mut slice = false;
+        let mut flags = vec![];
+        let fork = input.fork();
+        if fork
+            .parse::<Ident>()
+            .map_or(false, |ident| ident == "flags")
+            && fork.peek(token::Paren)
+        {
+            input.parse::<Ident>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            for flag in Punctuated::<Ident, Token![,]>::parse_terminated(&content)? {
+                if flag == "alloc" {
+                    alloc = true;
+                } else if flag == "arc" {
+                    arc = true;
+                } else if flag == "bound" {
+                    bound = true;
+                } else if flag == "iter" {
+                    iter = true;
+                } else if flag == "lock" {
+                    lock = true;
+                } else if flag == "rc" {
+                    rc = true;
+                } else if flag == "slice" {
+                    slice = true;
+                } else {
+                    return Err(Error::new(flag.span(), "Unrecognized flag"));
+                }
+                flags.push(flag);
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        // `iter` and `lock` replace the usual methods, which the other flags add to
+        for (mode, enabled) in [("iter", iter), ("lock", lock)] {
+            if !enabled {
+                continue;
+            }
+            let conflict = flags.iter().find(|flag| {
+                *flag != mode
+                    && ["arc", "rc", "slice", "iter", "lock"]
+                        .iter()
+                        .any(|f| *flag == f)
+            });
+            if let Some(flag) = conflict {
+                return Err(Error::new(
+                    flag.span(),
+                    format!("The `{flag}` flag is not supported with `{mode}`"),
+                ));
+            }
+        }
+
+        // loop over the input and parse traits
+        let targets = input
+            .parse_terminated(Target::parse, Token![,])?
             .into_iter()
-            .collect();
+            .collect::<Vec<_>>();
+
+        // targets with the same name would generate methods with the same names
+        let mut names = vec![];
+        for target in &targets {
+            let name = target_name(target)?;
+            if names.contains(&name) {
+                return Err(Error::new(
+                    target.bounds.span(),
+                    format!(
+                        "Another trait in the list already generates methods named like `as_{name}`"
+                    ),
+                ));
+            }
+            names.push(name);
+        }
 
-        Ok(Paths(paths))
+        Ok(Args {
+            vis,
+            alloc,
+            arc,
+            bound,
+            iter,
+            lock,
+            rc,
+            slice,
+            targets,
+        })
     }
 }
 
-fn make_impl(path: &Path, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
-    // construct the arms
-    let (as_arms, into_arms) = make_arms(&input_enum)?;
+/// Returns the name used in the method names of the target, joining the names of the bounds with underscores.
+fn target_name(target: &Target) -> syn::Result<String> {
+    use heck::ToSnakeCase;
 
+    let name = match &target.alias {
+        // the alias is converted like the names of the bounds so that the method names are in snake case
+        Some(alias) => alias.unraw().to_string().to_snake_case(),
+        None => {
+            let target_ident = target
+                .bounds
+                .iter()
+                .map(super::path_name)
+                .collect::<syn::Result<Vec<_>>>()?
+                .join("_");
+            format!("dyn_{target_ident}")
+        }
+    };
+    Ok(name)
+}
+
+fn make_impl(target: &Target, args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
     // construct the function names
-    let target_ident = path
-        .segments
-        .last()
-        .expect("empty path")
-        .ident
-        .to_string()
-        .to_snake_case();
-    let as_dyn = Ident::new(&format!("as_dyn_{target_ident}"), Span::call_site());
-    let as_dyn_mut = Ident::new(&format!("as_dyn_{target_ident}_mut"), Span::call_site());
-    let into_dyn = Ident::new(&format!("into_dyn_{target_ident}"), Span::call_site());
+    let bounds = &target.bounds;
+    let name = target_name(target)?;
+    let as_dyn = Ident::new(&format!("as_{name}"), Span::call_site());
+    let as_dyn_mut = Ident::new(&format!("as_{name}_mut"), Span::call_site());
+    let into_dyn = Ident::new(&format!("into_{name}"), Span::call_site());
+    let into_arc_dyn = Ident::new(&format!("into_arc_{name}"), Span::call_site());
+    let into_rc_dyn = Ident::new(&format!("into_rc_{name}"), Span::call_site());
+    let try_into_dyn = Ident::new(&format!("try_into_{name}"), Span::call_site());
+    let iter_dyn = Ident::new(&format!("iter_{name}"), Span::call_site());
+    let iter_dyn_mut = Ident::new(&format!("iter_{name}_mut"), Span::call_site());
+    let read_dyn = Ident::new(&format!("read_{name}"), Span::call_site());
+    let write_dyn = Ident::new(&format!("write_{name}"), Span::call_site());
+    let as_dyn_slice = Ident::new(&format!("as_{name}_slice"), Span::call_site());
+
+    // a trait object with multiple bounds is parenthesized to be used behind references
+    let path = bounds.to_token_stream();
+    let dyn_path = if bounds.len() > 1 {
+        quote::quote!((dyn #path))
+    } else {
+        quote::quote!(dyn #path)
+    };
+
+    let vis = &args.vis;
+    // the allocating types are taken from the alloc crate in no_std crates
+    let alloc = if args.alloc {
+        quote::quote!(::alloc)
+    } else {
+        quote::quote!(::std)
+    };
+
+    // calling the methods on a skipped variant panics
+    let panics_doc = super::skipped_panics_doc(input_enum)?;
+
+    // construct the bounds
+    let mut generics = input_enum.generics.clone();
+    if args.bound {
+        let params = delegate_type_params(input_enum)?;
+        if !params.is_empty() {
+            let predicates = generics.make_where_clause();
+            for param in &params {
+                predicates.predicates.push(syn::parse_quote!(#param: #path));
+            }
+        }
+    }
+    // owned trait objects live as long as the enum, which may borrow data
+    let owned_dyn_path = quote::quote!(dyn #path + '__dyn);
 
     // construct the impl
     let enum_ident = &input_enum.ident;
-    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
-    let enum_impl = quote::quote! {
-        impl #impl_generics #enum_ident #ty_generics #where_clause {
-            fn #as_dyn (&self) -> &dyn #path {
-                match self {
-                    #(#as_arms),*
+    let (impl_generics, ty_generics, where_clause) = &generics.split_for_impl();
+    if args.iter {
+        let iter_match = make_match(
+            input_enum,
+            &quote::quote! {
+                #alloc::boxed::Box::new(
+                    __first.iter().map(|__item| __item as &#dyn_path)
+                ) as #alloc::boxed::Box<dyn ::core::iter::Iterator<Item = &#dyn_path> + '_>
+            },
+            &iter_dyn,
+        )?;
+        let iter_mut_match = make_match(
+            input_enum,
+            &quote::quote! {
+                #alloc::boxed::Box::new(
+                    __first.iter_mut().map(|__item| __item as &mut #dyn_path)
+                ) as #alloc::boxed::Box<dyn ::core::iter::Iterator<Item = &mut #dyn_path> + '_>
+            },
+            &iter_dyn_mut,
+        )?;
+        let enum_impl = quote::quote! {
+            #[automatically_derived]
+            impl #impl_generics #enum_ident #ty_generics #where_clause {
+                #panics_doc
+                #vis fn #iter_dyn (&self) -> impl ::core::iter::Iterator<Item = &#dyn_path> + '_ {
+                    #iter_match
+                }
+                #panics_doc
+                #vis fn #iter_dyn_mut (&mut self) -> impl ::core::iter::Iterator<Item = &mut #dyn_path> + '_ {
+                    #iter_mut_match
+                }
+            }
+        };
+        return Ok(enum_impl);
+    }
+    if args.lock {
+        let read_match = make_match(
+            input_enum,
+            &quote::quote! {
+                #alloc::boxed::Box::new(__Guard(__first.read().unwrap(), ::core::marker::PhantomData))
+                    as #alloc::boxed::Box<dyn ::core::ops::Deref<Target = #owned_dyn_path> + '__dyn>
+            },
+            &read_dyn,
+        )?;
+        let write_match = make_match(
+            input_enum,
+            &quote::quote! {
+                #alloc::boxed::Box::new(__Guard(__first.write().unwrap(), ::core::marker::PhantomData))
+                    as #alloc::boxed::Box<dyn ::core::ops::DerefMut<Target = #owned_dyn_path> + '__dyn>
+            },
+            &write_dyn,
+        )?;
+        // the guards of the different field types are boxed behind a single type that dereferences to the trait object,
+        // which lives as long as the borrow of the enum so that the values in the locks may borrow data
+        let guard = quote::quote! {
+            struct __Guard<'__dyn, G>(G, ::core::marker::PhantomData<&'__dyn ()>);
+            impl<'__dyn, G> ::core::ops::Deref for __Guard<'__dyn, G>
+            where
+                G: ::core::ops::Deref,
+                G::Target: #path + Sized + '__dyn,
+            {
+                type Target = #owned_dyn_path;
+                fn deref(&self) -> &Self::Target {
+                    &*self.0
+                }
+            }
+            impl<'__dyn, G> ::core::ops::DerefMut for __Guard<'__dyn, G>
+            where
+                G: ::core::ops::DerefMut,
+                G::Target: #path + Sized + '__dyn,
+            {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut *self.0
                 }
             }
-            fn #as_dyn_mut (&mut self) -> &mut dyn #path {
-                match self {
-                    #(#as_arms),*
+            struct __Boxed<'__dyn, B>(B, ::core::marker::PhantomData<&'__dyn ()>);
+            impl<'__dyn, B> ::core::ops::Deref for __Boxed<'__dyn, B>
+            where
+                B: ::core::ops::Deref,
+                B::Target: ::core::ops::Deref<Target = #owned_dyn_path>,
+            {
+                type Target = #owned_dyn_path;
+                fn deref(&self) -> &Self::Target {
+                    &**self.0
                 }
             }
-            fn #into_dyn (self) -> Box<dyn #path> {
-                match self {
-                    #(#into_arms),*
+            impl<'__dyn, B> ::core::ops::DerefMut for __Boxed<'__dyn, B>
+            where
+                B: ::core::ops::DerefMut,
+                B::Target: ::core::ops::DerefMut<Target = #owned_dyn_path>,
+            {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut **self.0
                 }
             }
+        };
+        let enum_impl = quote::quote! {
+            #[automatically_derived]
+            impl #impl_generics #enum_ident #ty_generics #where_clause {
+                #panics_doc
+                #vis fn #read_dyn <'__dyn> (&'__dyn self) -> impl ::core::ops::Deref<Target = #owned_dyn_path> + '__dyn {
+                    #guard
+                    __Boxed(#read_match, ::core::marker::PhantomData)
+                }
+                #panics_doc
+                #vis fn #write_dyn <'__dyn> (&'__dyn self) -> impl ::core::ops::DerefMut<Target = #owned_dyn_path> + '__dyn {
+                    #guard
+                    __Boxed(#write_match, ::core::marker::PhantomData)
+                }
+            }
+        };
+        return Ok(enum_impl);
+    }
+    let as_match = make_match(input_enum, &quote::quote!(__first as _), &as_dyn)?;
+    let as_mut_match = make_match(input_enum, &quote::quote!(__first as _), &as_dyn_mut)?;
+    let into_match = make_match(
+        input_enum,
+        &quote::quote!(#alloc::boxed::Box::new(__first) as _),
+        &into_dyn,
+    )?;
+    let arc_fn = if args.arc {
+        let into_arc_match = make_match(
+            input_enum,
+            &quote::quote!(#alloc::sync::Arc::new(__first) as _),
+            &into_arc_dyn,
+        )?;
+        Some(quote::quote! {
+            #panics_doc
+            #vis fn #into_arc_dyn <'__dyn> (self) -> #alloc::sync::Arc<#owned_dyn_path> where Self: '__dyn {
+                #into_arc_match
+            }
+        })
+    } else {
+        None
+    };
+    let rc_fn = if args.rc {
+        let into_rc_match = make_match(
+            input_enum,
+            &quote::quote!(#alloc::rc::Rc::new(__first) as _),
+            &into_rc_dyn,
+        )?;
+        Some(quote::quote! {
+            #panics_doc
+            #vis fn #into_rc_dyn <'__dyn> (self) -> #alloc::rc::Rc<#owned_dyn_path> where Self: '__dyn {
+                #into_rc_match
+            }
+        })
+    } else {
+        None
+    };
+    // variants that don't implement the trait are returned as errors
+    let mut has_skipped = false;
+    for variant in &input_enum.variants {
+        has_skipped |= super::is_skipped(variant)?;
+    }
+    let try_into_fn = if has_skipped {
+        let try_into_match = make_match_or(
+            input_enum,
+            &quote::quote!(::core::result::Result::Ok(#alloc::boxed::Box::new(__first) as _)),
+            &quote::quote!(::core::result::Result::Err(__skipped)),
+        )?;
+        Some(quote::quote! {
+            #vis fn #try_into_dyn <'__dyn> (self) -> ::core::result::Result<#alloc::boxed::Box<#owned_dyn_path>, Self> where Self: '__dyn {
+                #try_into_match
+            }
+        })
+    } else {
+        None
+    };
+    let slice_fn = if args.slice {
+        Some(quote::quote! {
+            #panics_doc
+            #vis fn #as_dyn_slice (slice: &[Self]) -> #alloc::vec::Vec<&#dyn_path> {
+                slice.iter().map(Self::#as_dyn).collect()
+            }
+        })
+    } else {
+        None
+    };
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #panics_doc
+            #vis fn #as_dyn (&self) -> &#dyn_path {
+                #as_match
+            }
+            #panics_doc
+            #vis fn #as_dyn_mut (&mut self) -> &mut #dyn_path {
+                #as_mut_match
+            }
+            #panics_doc
+            #vis fn #into_dyn <'__dyn> (self) -> #alloc::boxed::Box<#owned_dyn_path> where Self: '__dyn {
+                #into_match
+            }
+            #arc_fn
+            #rc_fn
+            #try_into_fn
+            #slice_fn
         }
     };
     Ok(enum_impl)
 }
 
-fn make_arms(input_enum: &ItemEnum) -> syn::Result<(Vec<TokenStream2>, Vec<TokenStream2>)> {
-    let mut as_arms = vec![];
-    let mut into_arms = vec![];
-
+/// Makes a match expression with an arm for every variant
+/// that binds the first field to `__first` and evaluates the expression.
+/// The skipped variants panic with a message naming the method.
+fn make_match(
+    input_enum: &ItemEnum,
+    expr: &TokenStream2,
+    method: &Ident,
+) -> syn::Result<TokenStream2> {
+    let mut arms = vec![];
     for variant in &input_enum.variants {
-        let first_field = super::first_field(variant)?;
+        if super::is_skipped(variant)? {
+            arms.push(super::skipped_arm(variant, method));
+        } else {
+            arms.push(super::make_delegate_arm(variant, expr.clone())?);
+        }
+    }
+    Ok(super::make_match(&quote::quote!(self), &arms))
+}
 
-        let variant_ident = &variant.ident;
-        if let Some(first_field_ident) = &first_field.ident {
-            as_arms.push(quote::quote! {
-                Self::#variant_ident { #first_field_ident: __first, .. } => __first as _
-            });
-            into_arms.push(quote::quote! {
-                Self::#variant_ident { #first_field_ident: __first, .. } => Box::new(__first) as _
-            });
+/// Makes a match expression like [`make_match`] that binds the skipped variants to `__skipped`
+/// and evaluates the other expression for them.
+fn make_match_or(
+    input_enum: &ItemEnum,
+    expr: &TokenStream2,
+    skipped_expr: &TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let mut arms = vec![];
+    for variant in &input_enum.variants {
+        if super::is_skipped(variant)? {
+            let variant_ident = &variant.ident;
+            let cfg = super::cfg_attrs(variant);
+            let allow_deprecated = super::allow_deprecated(variant);
+            arms.push((
+                quote::quote!(#cfg #allow_deprecated __skipped @ Self::#variant_ident { .. }),
+                skipped_expr.clone(),
+            ));
         } else {
-            as_arms.push(quote::quote! {
-                Self::#variant_ident ( __first, .. ) => __first as _
-            });
-            into_arms.push(quote::quote! {
-                Self::#variant_ident ( __first, .. ) => Box::new(__first) as _
-            });
+            arms.push(super::make_delegate_arm(variant, expr.clone())?);
+        }
+    }
+    Ok(super::make_match(&quote::quote!(self), &arms))
+}
+
+/// Finds the generic type parameters of the enum that are used directly as the type of a delegate field.
+fn delegate_type_params(input_enum: &ItemEnum) -> syn::Result<Vec<&Ident>> {
+    let mut params = vec![];
+    for variant in &input_enum.variants {
+        if super::is_skipped(variant)? {
+            continue;
+        }
+        let (_, first_field) = super::delegate_field(variant, None)?;
+
+        let field_ident = match &first_field.ty {
+            Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident(),
+            _ => None,
         };
+        let field_ident = match field_ident {
+            Some(field_ident) => field_ident,
+            None => continue,
+        };
+        let param = input_enum.generics.params.iter().find_map(|gp| match gp {
+            GenericParam::Type(tp) if &tp.ident == field_ident => Some(&tp.ident),
+            _ => None,
+        });
+        if let Some(param) = param {
+            if !params.contains(&param) {
+                params.push(param);
+            }
+        }
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(arg: TokenStream2) -> String {
+        match super::super::parse_args::<Args>(
+            arg,
+            "Expected a comma-separated list of trait paths",
+        ) {
+            Ok(_) => panic!("the arguments were parsed"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn missing_comma() {
+        assert_eq!(
+            "Expected a comma-separated list of trait paths: expected `,`",
+            parse_error(quote::quote!(std::fmt::Debug std::fmt::Display))
+        );
+    }
+
+    #[test]
+    fn duplicate_name() {
+        assert_eq!(
+            "Another trait in the list already generates methods named like `as_dyn_write`",
+            parse_error(quote::quote!(Write, std::io::Write))
+        );
+        assert_eq!(
+            "Another trait in the list already generates methods named like `as_debug`",
+            parse_error(quote::quote!(Debug as debug, Display as debug))
+        );
     }
 
-    Ok((as_arms, into_arms))
+    /// Traits named like the flags are targets outside of the `flags(...)` group.
+    #[test]
+    fn flags() {
+        let args: Args = syn::parse_quote!(flags(arc, slice), iter, lock::Lock);
+        assert!(args.arc && args.slice && !args.iter && !args.lock);
+        assert_eq!(2, args.targets.len());
+        assert_eq!(
+            "Unrecognized flag",
+            parse_error(quote::quote!(flags(arc, unknown), Debug))
+        );
+    }
+
+    #[test]
+    fn conflicting_flags() {
+        assert_eq!(
+            "The `arc` flag is not supported with `iter`",
+            parse_error(quote::quote!(flags(iter, arc), Debug))
+        );
+        assert_eq!(
+            "The `slice` flag is not supported with `lock`",
+            parse_error(quote::quote!(flags(slice, lock), Debug))
+        );
+        assert_eq!(
+            "The `lock` flag is not supported with `iter`",
+            parse_error(quote::quote!(flags(lock, iter), Debug))
+        );
+    }
 }
@@ -0,0 +1,6 @@
+use proc_macro::TokenStream;
+
+pub fn as_mut_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    // the methods are generated like the mutable counterparts of as_ref
+    super::as_ref::expand(arg, input, true)
+}
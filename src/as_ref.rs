@@ -0,0 +1,202 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, ItemEnum, Meta, Token, Type,
+};
+
+pub fn as_ref_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    expand(arg, input, false)
+}
+
+/// Expands the `as_ref` macro, or the `as_mut` macro which only generates the mutable counterparts.
+pub fn expand(arg: TokenStream, input: TokenStream, mut_only: bool) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    if mut_only && args.as_mut {
+        return Error::new(
+            Span::call_site(),
+            "The `as_mut` flag is implied by the `as_mut` macro",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let mutabilities = if mut_only {
+        &[true][..]
+    } else if args.as_mut {
+        &[false, true][..]
+    } else {
+        &[false][..]
+    };
+
+    let mut methods = vec![];
+    let mut trait_impls = vec![];
+    for target in &args.targets {
+        for &mutable in mutabilities {
+            let result = if args.trait_impls {
+                make_trait_impl(target, mutable, &input_enum).map(|i| trait_impls.push(i))
+            } else {
+                make_method(target, mutable, &input_enum).map(|m| methods.push(m))
+            };
+            if let Err(err) = result {
+                return err.into_compile_error().into();
+            }
+        }
+    }
+
+    // construct the impl
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote!(#[cfg(#cfg)]));
+    let enum_impl = if args.trait_impls {
+        quote::quote! {
+            #(
+                #cfg
+                #trait_impls
+            )*
+        }
+    } else {
+        quote::quote! {
+            #cfg
+            #[automatically_derived]
+            impl #impl_generics #enum_ident #ty_generics #where_clause {
+                #(#methods)*
+            }
+        }
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    targets: Vec<Type>,
+    /// The predicate the impl is conditionally compiled with.
+    cfg: Option<Meta>,
+    /// Implement `AsRef` or `AsMut` for the enum instead of generating methods.
+    trait_impls: bool,
+    /// Also generate the mutable counterparts using `AsMut`.
+    as_mut: bool,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        // loop over the input and parse types and options
+        let mut targets = vec![];
+        let mut cfg = None;
+        let mut trait_impls = false;
+        let mut as_mut = false;
+        while !input.is_empty() {
+            if let Some(predicate) = super::parse_cfg_option(input)? {
+                cfg = Some(predicate);
+            } else {
+                let target = input.parse()?;
+                match &target {
+                    Type::Path(path)
+                        if path.qself.is_none() && path.path.is_ident("trait_impls") =>
+                    {
+                        trait_impls = true
+                    }
+                    Type::Path(path) if path.qself.is_none() && path.path.is_ident("as_mut") => {
+                        as_mut = true
+                    }
+                    _ => targets.push(target),
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Args {
+            targets,
+            cfg,
+            trait_impls,
+            as_mut,
+        })
+    }
+}
+
+fn make_method(target: &Type, mutable: bool, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let body = make_body(target, mutable, input_enum)?;
+    let (prefix, mutability) = if mutable {
+        ("as_mut", Some(quote::quote!(mut)))
+    } else {
+        ("as_ref", None)
+    };
+    let method_ident = Ident::new(
+        &format!("{}_{}", prefix, super::type_name(target)?),
+        Span::call_site(),
+    );
+    let method = quote::quote! {
+        fn #method_ident (& #mutability self) -> & #mutability #target {
+            #body
+        }
+    };
+    Ok(method)
+}
+
+fn make_trait_impl(
+    target: &Type,
+    mutable: bool,
+    input_enum: &ItemEnum,
+) -> syn::Result<TokenStream2> {
+    let body = make_body(target, mutable, input_enum)?;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let trait_impl = if mutable {
+        quote::quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::AsMut<#target> for #enum_ident #ty_generics #where_clause {
+                fn as_mut(&mut self) -> &mut #target {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote::quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::AsRef<#target> for #enum_ident #ty_generics #where_clause {
+                fn as_ref(&self) -> &#target {
+                    #body
+                }
+            }
+        }
+    };
+    Ok(trait_impl)
+}
+
+/// Matches on `self` and converts the variant's field to a reference to the target.
+fn make_body(target: &Type, mutable: bool, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    // construct the arms
+    let mut arms = vec![];
+    for variant in &input_enum.variants {
+        let (first_member, first_field) = super::delegate_field(variant, None)?;
+
+        let variant_ident = &variant.ident;
+        let cfg = super::cfg_attrs(variant);
+        let allow_deprecated = super::allow_deprecated(variant);
+        let first_field_type = &first_field.ty;
+        let call = if mutable {
+            quote::quote! {
+                <#first_field_type as ::core::convert::AsMut<#target>>::as_mut(__first)
+            }
+        } else {
+            quote::quote! {
+                <#first_field_type as ::core::convert::AsRef<#target>>::as_ref(__first)
+            }
+        };
+        arms.push((
+            quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #first_member: __first, .. }),
+            call,
+        ));
+    }
+
+    Ok(super::make_match(&quote::quote!(self), &arms))
+}
@@ -0,0 +1,174 @@
+//! Tests for code that should fail to compile, run as doctests.
+
+/// With `check`, a variant whose field type doesn't implement the trait is reported at the variant.
+/// ```compile_fail,E0277
+/// trait Named {
+///     fn name(&self) -> String;
+/// }
+/// struct Square;
+/// struct Circle;
+/// impl Named for Square {
+///     fn name(&self) -> String {
+///         "square".to_string()
+///     }
+/// }
+///
+/// #[impl_enum::with_methods(check, {
+///     fn name(&self) -> String in Named
+/// })]
+/// enum Shape {
+///     Square(Square),
+///     Circle(Circle),
+/// }
+/// ```
+#[cfg(feature = "with_methods")]
+struct WithMethodsCheck;
+
+/// The field type must implement `Default`.
+/// ```compile_fail,E0277
+/// struct NoDefault;
+///
+/// #[impl_enum::take_or_default]
+/// enum Slot {
+///     Empty,
+///     Full(NoDefault),
+/// }
+/// ```
+#[cfg(feature = "take_or_default")]
+struct TakeOrDefaultNoDefault;
+
+/// Every variant must have a corresponding variant in the target enum.
+/// ```compile_fail,E0599
+/// enum New {
+///     A(u8),
+/// }
+///
+/// #[impl_enum::map_to(New)]
+/// enum Old {
+///     A(u8),
+///     B(u8),
+/// }
+/// ```
+#[cfg(feature = "map_to")]
+struct MapToMissingVariant;
+
+/// Attributes on variants are kept, so using a deprecated variant warns.
+/// ```compile_fail
+/// #![deny(deprecated)]
+///
+/// #[impl_enum::with_methods {
+///     fn len(&self) -> usize
+/// }]
+/// #[impl_enum::as_dyn(std::fmt::Debug)]
+/// enum Value {
+///     Text(String),
+///     #[deprecated]
+///     Old(String),
+/// }
+///
+/// let _ = Value::Old(String::new());
+/// ```
+#[cfg(all(feature = "with_methods", feature = "as_dyn"))]
+struct VariantDeprecated;
+
+/// Restricted visibilities on signatures are kept, so the method is private outside of the given path.
+/// ```compile_fail,E0624
+/// mod outer {
+///     pub mod inner {
+///         #[impl_enum::with_methods {
+///             pub(in crate::outer) fn len(&self) -> usize
+///         }]
+///         pub enum Buffer {
+///             Vec(Vec<u8>),
+///         }
+///     }
+/// }
+///
+/// fn len(buffer: &outer::inner::Buffer) -> usize {
+///     buffer.len()
+/// }
+///
+/// fn main() {}
+/// ```
+#[cfg(feature = "with_methods")]
+struct WithMethodsRestrictedVisibility;
+
+/// Only the match arms of deprecated variants allow deprecation warnings,
+/// so calling a deprecated method of a field type is still reported.
+/// ```compile_fail
+/// #![deny(deprecated)]
+///
+/// pub struct Field;
+///
+/// impl Field {
+///     #[deprecated]
+///     fn len(&self) -> usize {
+///         0
+///     }
+/// }
+///
+/// #[impl_enum::with_methods {
+///     pub fn len(&self) -> usize
+/// }]
+/// pub enum Value {
+///     Field(Field),
+/// }
+/// ```
+#[cfg(feature = "with_methods")]
+struct WithMethodsDeprecatedMethod;
+
+/// An enum with methods taking `self: Pin<&mut Self>` can't implement `Unpin` itself,
+/// which would allow moving a pinned field that isn't `Unpin`.
+/// ```compile_fail,E0119
+/// use std::{marker::PhantomPinned, pin::Pin};
+///
+/// trait Resume {
+///     fn resume(self: Pin<&mut Self>);
+/// }
+///
+/// struct Pinned(PhantomPinned);
+///
+/// impl Resume for Pinned {
+///     fn resume(self: Pin<&mut Self>) {}
+/// }
+///
+/// #[impl_enum::with_methods(trait_impls, {
+///     fn resume(self: Pin<&mut Self>) in Resume
+/// })]
+/// enum Task {
+///     Pinned(Pinned),
+/// }
+///
+/// impl Unpin for Task {}
+/// ```
+#[cfg(feature = "with_methods")]
+struct WithMethodsPinUnpin;
+
+/// An enum with methods taking `self: Pin<&mut Self>` can't implement `Drop`,
+/// which could move a pinned field.
+/// ```compile_fail,E0119
+/// use std::{marker::PhantomPinned, pin::Pin};
+///
+/// trait Resume {
+///     fn resume(self: Pin<&mut Self>);
+/// }
+///
+/// struct Pinned(PhantomPinned);
+///
+/// impl Resume for Pinned {
+///     fn resume(self: Pin<&mut Self>) {}
+/// }
+///
+/// #[impl_enum::with_methods(trait_impls, {
+///     fn resume(self: Pin<&mut Self>) in Resume
+/// })]
+/// enum Task {
+///     Pinned(Pinned),
+/// }
+///
+/// impl Drop for Task {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+#[cfg(feature = "with_methods")]
+struct WithMethodsPinDrop;
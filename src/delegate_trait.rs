@@ -0,0 +1,69 @@
+use proc_macro::TokenStream;
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    token, Attribute, Error, Path, Signature, Token,
+};
+
+pub fn delegate_trait_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+
+    // the trait is implemented with the trait_impls option of with_methods
+    let trait_path = &args.trait_path;
+    let methods = args.methods.iter().map(|(attrs, sig)| {
+        quote::quote! {
+            #(#attrs)*
+            #sig in #trait_path;
+        }
+    });
+    let with_methods_args = quote::quote! {
+        trait_impls, { #(#methods)* }
+    };
+    super::with_methods::with_methods_impl(with_methods_args.into(), input)
+}
+
+struct Args {
+    trait_path: Path,
+    /// The signatures of the methods of the trait, along with their attributes.
+    methods: Vec<(Vec<Attribute>, Signature)>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        // the trait is followed by the signatures of its methods in braces
+        let trait_path: Path = input.parse()?;
+        if !input.peek(token::Brace) {
+            return Err(Error::new(
+                trait_path.span(),
+                "Expected the signatures of the methods to delegate in braces after the trait",
+            ));
+        }
+        let content;
+        syn::braced!(content in input);
+
+        let mut methods = vec![];
+        while !content.is_empty() {
+            let attrs = content.call(Attribute::parse_outer)?;
+            let sig: Signature = content.parse()?;
+            if sig.receiver().is_none() {
+                return Err(Error::new(
+                    sig.span(),
+                    "Only methods with a receiver can be delegated",
+                ));
+            }
+            methods.push((attrs, sig));
+            content.parse::<Option<Token![;]>>()?;
+        }
+        if methods.is_empty() {
+            return Err(Error::new(
+                trait_path.span(),
+                "Expected the signatures of the methods to delegate",
+            ));
+        }
+
+        Ok(Args {
+            trait_path,
+            methods,
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Nothing, ItemEnum};
+
+pub fn from_variants_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(arg as Nothing);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let from_impls = match make_impls(&input_enum) {
+        Ok(from_impls) => from_impls,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impls
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #(#from_impls)*
+    })
+}
+
+fn make_impls(input_enum: &ItemEnum) -> syn::Result<Vec<TokenStream2>> {
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+
+    // construct an impl for every variant that isn't skipped
+    let mut from_impls = vec![];
+    for variant in &input_enum.variants {
+        if super::is_skipped(variant)? {
+            continue;
+        }
+        let field = super::only_field(variant)?;
+        let (member, _) = super::delegate_field(variant, None)?;
+        let variant_ident = &variant.ident;
+        let field_type = &field.ty;
+        let cfg = super::cfg_attrs(variant);
+        // the impl belongs to the variant, so it can use the variant if it's deprecated
+        let allow_deprecated = super::allow_deprecated(variant);
+        from_impls.push(quote::quote! {
+            #cfg
+            #allow_deprecated
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#field_type> for #enum_ident #ty_generics #where_clause {
+                fn from(value: #field_type) -> Self {
+                    Self::#variant_ident { #member: value }
+                }
+            }
+        });
+    }
+    Ok(from_impls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_error(input_enum: ItemEnum) -> String {
+        match make_impls(&input_enum) {
+            Ok(_) => panic!("the impls were made"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn field_count() {
+        assert_eq!(
+            "The unit variant `Empty` is not supported",
+            make_error(syn::parse_quote! {
+                enum Value {
+                    Bytes(Vec<u8>),
+                    Empty,
+                }
+            })
+        );
+        assert_eq!(
+            "The variant `Empty` must have at least one field",
+            make_error(syn::parse_quote! {
+                enum Value {
+                    Bytes(Vec<u8>),
+                    Empty(),
+                }
+            })
+        );
+        assert_eq!(
+            "The variant `Pair` must have exactly one field",
+            make_error(syn::parse_quote! {
+                enum Value {
+                    Bytes(Vec<u8>),
+                    Pair { first: u8, second: u8 },
+                }
+            })
+        );
+    }
+}
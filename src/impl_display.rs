@@ -0,0 +1,41 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Nothing, ItemEnum};
+
+pub fn impl_display_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(arg as Nothing);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+fn make_impl(input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let display = quote::quote!(::core::fmt::Display);
+
+    let fmt_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#display::fmt(__first, f)))?;
+
+    // construct the impl
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #display for #enum_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #fmt_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
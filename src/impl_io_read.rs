@@ -0,0 +1,64 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Nothing, ItemEnum};
+
+pub fn impl_io_read_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(arg as Nothing);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+fn make_impl(input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let read = quote::quote!(::std::io::Read);
+    let read_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#read::read(__first, buf)))?;
+    let read_vectored_match = super::make_delegate_match(
+        input_enum,
+        &quote::quote!(#read::read_vectored(__first, bufs)),
+    )?;
+    let read_to_end_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#read::read_to_end(__first, buf)))?;
+    let read_to_string_match = super::make_delegate_match(
+        input_enum,
+        &quote::quote!(#read::read_to_string(__first, buf)),
+    )?;
+    let read_exact_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#read::read_exact(__first, buf)))?;
+
+    // construct the impl
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #read for #enum_ident #ty_generics #where_clause {
+            fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+                #read_match
+            }
+            fn read_vectored(&mut self, bufs: &mut [::std::io::IoSliceMut<'_>]) -> ::std::io::Result<usize> {
+                #read_vectored_match
+            }
+            fn read_to_end(&mut self, buf: &mut ::std::vec::Vec<u8>) -> ::std::io::Result<usize> {
+                #read_to_end_match
+            }
+            fn read_to_string(&mut self, buf: &mut ::std::string::String) -> ::std::io::Result<usize> {
+                #read_to_string_match
+            }
+            fn read_exact(&mut self, buf: &mut [u8]) -> ::std::io::Result<()> {
+                #read_exact_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
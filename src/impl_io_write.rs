@@ -0,0 +1,62 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Nothing, ItemEnum};
+
+pub fn impl_io_write_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(arg as Nothing);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+fn make_impl(input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let write = quote::quote!(::std::io::Write);
+    let write_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#write::write(__first, buf)))?;
+    let write_vectored_match = super::make_delegate_match(
+        input_enum,
+        &quote::quote!(#write::write_vectored(__first, bufs)),
+    )?;
+    let flush_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#write::flush(__first)))?;
+    let write_all_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#write::write_all(__first, buf)))?;
+    let write_fmt_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#write::write_fmt(__first, fmt)))?;
+
+    // construct the impl
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #write for #enum_ident #ty_generics #where_clause {
+            fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+                #write_match
+            }
+            fn write_vectored(&mut self, bufs: &[::std::io::IoSlice<'_>]) -> ::std::io::Result<usize> {
+                #write_vectored_match
+            }
+            fn flush(&mut self) -> ::std::io::Result<()> {
+                #flush_match
+            }
+            fn write_all(&mut self, buf: &[u8]) -> ::std::io::Result<()> {
+                #write_all_match
+            }
+            fn write_fmt(&mut self, fmt: ::core::fmt::Arguments<'_>) -> ::std::io::Result<()> {
+                #write_fmt_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
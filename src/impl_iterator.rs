@@ -0,0 +1,70 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, Ident, ItemEnum, Token, Type,
+};
+
+pub fn impl_iterator_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    /// The associated `Item` type of the impl.
+    item: Type,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let ident = input.parse::<Ident>()?;
+        if ident != "Item" {
+            return Err(Error::new(ident.span(), "Expected `Item = Type`"));
+        }
+        input.parse::<Token![=]>()?;
+        let item = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Args { item })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let iterator = quote::quote!(::core::iter::Iterator);
+    let next_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#iterator::next(__first)))?;
+    let size_hint_match =
+        super::make_delegate_match(input_enum, &quote::quote!(#iterator::size_hint(__first)))?;
+
+    // construct the impl
+    let item = &args.item;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #iterator for #enum_ident #ty_generics #where_clause {
+            type Item = #item;
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                #next_match
+            }
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                #size_hint_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
@@ -0,0 +1,70 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, ItemEnum, Meta, Token,
+};
+
+pub fn inner_ref_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    /// The predicate the impl is conditionally compiled with.
+    cfg: Option<Meta>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let cfg = super::parse_cfg_option(input)?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(input.error("Unrecognized option"));
+        }
+
+        Ok(Args { cfg })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let field_type = super::common_field_type(input_enum)?;
+
+    // the match is the same for every receiver
+    let field_match = super::make_delegate_match(input_enum, &quote::quote!(__first))?;
+
+    // construct the impl
+    let vis = &input_enum.vis;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote!(#[cfg(#cfg)]));
+    let enum_impl = quote::quote! {
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis fn inner(&self) -> &#field_type {
+                #field_match
+            }
+            #vis fn inner_mut(&mut self) -> &mut #field_type {
+                #field_match
+            }
+            #vis fn into_inner(self) -> #field_type {
+                #field_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
@@ -13,16 +13,224 @@
 
 #[cfg(feature = "as_dyn")]
 mod as_dyn;
+#[cfg(feature = "as_mut")]
+mod as_mut;
+#[cfg(feature = "as_ref")]
+mod as_ref;
+#[cfg(doctest)]
+mod compile_fail;
+#[cfg(feature = "delegate_trait")]
+mod delegate_trait;
+#[cfg(feature = "from_variants")]
+mod from_variants;
+#[cfg(feature = "impl_display")]
+mod impl_display;
+#[cfg(feature = "impl_io_read")]
+mod impl_io_read;
+#[cfg(feature = "impl_io_write")]
+mod impl_io_write;
+#[cfg(feature = "impl_iterator")]
+mod impl_iterator;
+#[cfg(feature = "inner_ref")]
+mod inner_ref;
+#[cfg(feature = "map_inner")]
+mod map_inner;
+#[cfg(feature = "map_to")]
+mod map_to;
+#[cfg(feature = "take_or_default")]
+mod take_or_default;
+#[cfg(feature = "try_into_variants")]
+mod try_into_variants;
+#[cfg(feature = "try_parse")]
+mod try_parse;
+#[cfg(feature = "variant_helpers")]
+mod variant_helpers;
+#[cfg(feature = "variant_names")]
+mod variant_names;
+#[cfg(feature = "with_field")]
+mod with_field;
 #[cfg(feature = "with_methods")]
 mod with_methods;
 
+// the imports and helpers are shared by the macros, so they are only compiled with at least one of them
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
 use proc_macro::TokenStream;
-use syn::{spanned::Spanned, Error, Field, Fields, Variant};
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+use proc_macro2::TokenStream as TokenStream2;
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+use syn::{
+    spanned::Spanned, Attribute, Error, Field, Fields, Index, ItemEnum, Member, Token, Variant,
+};
 
 /// Generates methods for an enum that match on the enum
 /// and call given the method with the variant's first field.
 ///
-/// Takes a list of whitespace separated function signatures as its arguments.
+/// Takes a list of function signatures as its arguments,
+/// separated by whitespace, semicolons or commas.
+/// A signature with a `where` clause should be followed by a semicolon
+/// rather than a comma, which would continue the `where` clause.
+/// Arguments with patterns such as `(x, y): (usize, usize)` are renamed to `__arg{index}`
+/// in the generated method so that they can be passed to the delegated call.
+/// A signature can be followed by `= name` to delegate to a method with a different name,
+/// for example `fn inner_len(&self) -> usize = len` generates `inner_len` which calls `len` on the fields.
+/// This is not supported for the methods of trait impls, which must have the names of the trait's methods.
+/// A signature can be followed by `in Trait` to call the method from the given trait,
+/// for example `fn len(&self) -> usize in MyTrait` calls `<Field as MyTrait>::len`.
+/// This also disambiguates between traits of the field type that have methods with the same name.
+/// Otherwise, methods with a receiver are called with method call syntax, so that methods are found through auto-deref,
+/// for example for a `Box<dyn Write>` field, and associated functions are called on the field type.
+/// `via Trait` can be used instead of `in Trait`.
+/// The trait may use the generic parameters of the method, in which case every field type must be bounded
+/// by the trait in the `where` clause of the signature, for example
+/// `fn convert<T>(&self) -> T where A: Convert<T>, B: Convert<T> in Convert<T>`.
+/// The generic parameters and `where` clause of a method are kept in the generated method,
+/// and the generic arguments are passed to the delegated call if they can't be inferred from its arguments and return type.
+///
+/// Options can be passed before the signatures by wrapping the signatures in braces,
+/// for example `#[with_methods(check, { fn len(&self) -> usize in MyTrait })]`:
+/// - `check`: checks that the field type of every variant has each method with `in Trait` in the trait
+///   before delegating, so that a missing method or implementation is reported at the variant's field.
+///   Methods with generic type or const parameters are not checked.
+/// - `trait_impls`: the methods with `in Trait` are generated in an `impl Trait for Enum` block per trait
+///   instead of the inherent impl, so that the enum can be used as `dyn Trait`.
+///   The visibility of these methods is ignored and they must have a receiver.
+/// - `ref_mut_impls`: with `trait_impls`, each trait is also implemented for `&mut Enum`
+///   by forwarding to the impl of the enum, so that `&mut enum` can be passed to functions generic over the trait.
+///   Only supported for methods that take `&mut self`.
+///   Traits with a blanket impl for mutable references, such as [`std::io::Write`], already cover `&mut Enum`
+///   and conflict with the generated impl.
+/// - `field = member`: delegates to the field with the given index or name instead of the first field
+///   in every variant, for example `field = 1` for the second field of each variant.
+///   An index selects the field by position in both tuple and named variants.
+/// - `alloc`: the generated code refers to `Box`, `Vec`, `Rc` and `Arc` through the `alloc` crate instead of `std`,
+///   for use in `no_std` crates that declare `extern crate alloc;` at the crate root.
+///
+/// If a method returns `Self` or the enum type, such as `Enum<T>`,
+/// the result of the delegated call is wrapped back into the variant it was called on.
+/// The other fields of the variant are moved into the new value, which requires the method to take `self`
+/// for variants with more than one field.
+/// Similarly, if a method returns `Option<Self>` or `Result<Self, E>`, the value in the delegated call's result
+/// is wrapped back into the variant, while `None` and errors are returned as is.
+/// If a method returns `Vec<Self>`, each item is wrapped back into the variant,
+/// which requires every variant to have exactly one field.
+///
+/// A `const fn` stays `const`, which requires the delegated methods to be inherent `const fn`s of the field types.
+/// An `unsafe fn` stays `unsafe`, and the delegated calls are made in its body without an additional `unsafe` block.
+/// A method taking `self: Box<Self>` moves the field out of the box and calls the delegated method
+/// with the field in a new box, so the delegated methods must also take `self: Box<Self>`.
+/// A method taking `self: Pin<&mut Self>`, such as a `poll` method, calls the delegated method with the field pinned.
+/// The fields are pinned structurally, so the macro implements `Unpin` for the enum if the delegate field types are `Unpin`,
+/// and an impl of `Unpin` or `Drop` for the enum is rejected as a conflicting impl,
+/// as either could be used to move a pinned field. As a result, such methods must all be listed in one invocation.
+/// An `async fn` awaits the delegated call, so the delegated method can be an `async fn` of the field type
+/// or of a trait such as `fn send(&mut self, msg: Msg) -> Result<()> in Connection`.
+///
+/// Other attributes on a signature, such as `#[inline]`, `#[must_use]` or `#[allow(...)]`,
+/// are forwarded to the generated method, as are doc comments, so the generated methods can be documented.
+///
+/// Signatures can be annotated with `#[impl_enum(...)]` to pass the following options:
+/// - `tait`: an `async fn` returns a named future type `{Enum}{Method}Future` instead of an anonymous one,
+///   which can be stored without boxing. Requires the `nightly` feature of this crate
+///   and `#![feature(type_alias_impl_trait)]` in the crate using the macro.
+///   Not supported for the methods implemented in trait impls with `trait_impls`.
+/// - `box_error`: for a method returning `Result<T, Box<dyn Error>>`, the errors of the delegated calls are converted
+///   with `Into` so that variants with different error types can be used without `From` implementations.
+///   Concrete errors are boxed, which allocates a new box for every error, and errors that are already boxed are kept.
+/// - `hook = path`: the macro at the path is invoked with the name of the method as a string literal
+///   before delegating, for example `hook = my_crate::record` invokes `my_crate::record!("write");`.
+///   The macro must expand to a statement or an expression, such as
+///   `macro_rules! record { ($method:literal) => { println!("called {}", $method) }; }`.
+/// - `tagged`: the method returns a tuple of the index of the variant it was called on and the result,
+///   for example `(usize, std::io::Result<usize>)` for `fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>`.
+///   The index is the position of the variant in the enum definition, starting from zero.
+///   Not supported for the methods implemented in trait impls with `trait_impls`.
+/// - `through = method`: the method is called on the result of the given method of the enum instead of matching
+///   on the variants, for example `through = as_dyn_iterator_mut` with [macro@as_dyn] to forward the provided methods
+///   of a trait through the trait object. Not supported with `tait`, `tagged` or methods returning `Self`.
+/// - `wrap`: for a method returning `Box<dyn Trait>`, `Rc<dyn Trait>` or `Arc<dyn Trait>`,
+///   the result of the delegated call is moved into the smart pointer of the return type,
+///   so that the delegated methods can return different concrete types.
+/// - `parts`: for a method taking `self` and returning `(Field, R)`, the delegated method is called with a reference
+///   to the field, after which the field is moved out of the enum and returned along with the result.
+///   The delegated method must take `&self` and return `R`, and all of the variants must have the same field type.
+///   Not supported with `access`.
+///
+/// Variants can be annotated with `#[impl_enum(...)]` to pass the following options:
+/// - `access = |field| expr`: the method is called on the result of the expression instead of the first field,
+///   for example `|shared| shared.lock().unwrap()` for a field of type `Arc<Mutex<W>>`.
+///   Any bounds required by the delegated calls, such as `W: Write`, are taken from the enum definition.
+/// - `field = member`: delegates to the field with the given index or name instead of the first field,
+///   overriding the `field` option of the macro.
+/// - `passthrough`: the variant is returned unchanged instead of delegating the call.
+///   Only supported for methods that take `self` and return `Self`. The variant may be a unit variant.
+/// - `default = expr`: the expression is evaluated instead of delegating the call, which allows unit variants.
+///   The expression must have the return type of every method. Without an expression,
+///   `Default::default()` is used, for example `()` for methods that don't return a value.
+/// - `skip`: the variant is left out of the generated methods, which may be a unit variant
+///   or have fields that don't implement the methods. Calling a generated method on a skipped variant panics
+///   with a message naming the variant and the method, which is documented in a `# Panics` section of the method.
+///
+/// A field can be annotated with `#[impl_enum(delegate)]` or `#[impl_enum(to)]` to delegate to it
+/// instead of the first field of its variant, or a variant can be annotated with `#[impl_enum(to = name)]`
+/// to delegate to the field with the given name or index.
+/// These take precedence over the `field` options. Only one field per variant can be marked.
+///
+/// The `#[cfg]` attributes of a variant are attached to the match arms generated for it,
+/// so that they are removed along with the variant. The same applies to the other macros of this crate.
 ///
 /// # Example
 /// ```
@@ -39,6 +247,8 @@ use syn::{spanned::Spanned, Error, Field, Fields, Variant};
 ///             Self::File { file, .. } => file.write_all(buf),
 ///         }
 ///     }
+///     /// Writes a buffer into the writer,
+///     /// returning how many bytes were written.
 ///     pub fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
 ///         match self {
 ///             Self::Cursor(first, ..) => first.write(buf),
@@ -57,7 +267,14 @@ pub fn with_methods(args: TokenStream, input: TokenStream) -> TokenStream {
 /// and return the variant's first field as a trait object.
 ///
 /// Takes a comma-separated list of traits as an argument.
-/// The name of the trait is snake_cased for the method names.
+/// The name of the trait is snake_cased for the method names,
+/// treating runs of capital letters as acronyms, so that `HTTPClient` becomes `http_client`.
+/// The names of generic type arguments of the trait are appended, so that `AsRef<str>` becomes `as_ref_str`.
+/// A trait can be combined with additional bounds such as `Write + Send`, in which case the names are joined,
+/// generating `as_dyn_write_send` returning `&(dyn Write + Send)`. The bounds apply to all of the generated methods,
+/// so that for example `Job + Send + Sync` generates `into_dyn_job_send_sync` for moving the trait object
+/// to another thread. As trait objects can only have one trait besides auto traits,
+/// combining traits like `Read + Write` requires a supertrait of both.
 /// For example, for the trait `ExampleTrait`  it would generate
 /// ```
 /// # trait ExampleTrait {}
@@ -67,10 +284,104 @@ pub fn with_methods(args: TokenStream, input: TokenStream) -> TokenStream {
 /// # { unimplemented!() }
 /// fn as_dyn_example_trait_mut(&mut self) -> &mut dyn ExampleTrait
 /// # { unimplemented!() }
-/// fn into_dyn_example_trait(self) -> Box<dyn ExampleTrait>
+/// fn into_dyn_example_trait<'a>(self) -> Box<dyn ExampleTrait + 'a> where Self: 'a
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// The owned trait objects live as long as the enum, so an enum that borrows data, such as `Enum<'a>`,
+/// can be converted as well.
+///
+/// A field can be annotated with `#[impl_enum(delegate)]` or `#[impl_enum(to)]` to use it
+/// instead of the first field of its variant, or a variant can be annotated with `#[impl_enum(to = name)]`
+/// to use the field with the given name or index.
+/// A variant annotated with `#[impl_enum(skip)]` is left out of the generated methods,
+/// so calling them on the variant panics with a message naming the variant and the method,
+/// which is documented in a `# Panics` section of the methods. If any variant is skipped, the macro also generates
+/// ```
+/// # trait ExampleTrait {}
+/// # struct S;
+/// # impl S {
+/// fn try_into_dyn_example_trait<'a>(self) -> Result<Box<dyn ExampleTrait + 'a>, Self> where Self: 'a
 /// # { unimplemented!() }
 /// # }
 /// ```
+/// which returns the skipped variants back as errors.
+///
+/// A trait can be followed by `as name` to use the name instead of `dyn_{trait}` in the method names,
+/// so that for example `MyHttpClient as client` generates `as_client`, `as_client_mut` and `into_client`.
+/// The name is converted to snake case, so `MyHttpClient as Client` generates the same methods.
+///
+/// The methods are private by default. A visibility followed by a semicolon can be given before the list,
+/// for example `#[as_dyn(pub; ExampleTrait)]`, to apply it to all of the generated methods.
+///
+/// The following flags can be given in a `flags(...)` group at the start of the list,
+/// for example `#[as_dyn(flags(arc, rc), ExampleTrait)]`, so that they can't be confused with trait paths:
+/// - `arc`: additionally generates
+///   ```
+///   # trait ExampleTrait {}
+///   # struct S;
+///   # impl S {
+///   fn into_arc_dyn_example_trait<'a>(self) -> std::sync::Arc<dyn ExampleTrait + 'a> where Self: 'a
+///   # { unimplemented!() }
+///   # }
+///   ```
+///   for sharing the trait object, for example across threads.
+/// - `rc`: additionally generates
+///   ```
+///   # trait ExampleTrait {}
+///   # struct S;
+///   # impl S {
+///   fn into_rc_dyn_example_trait<'a>(self) -> std::rc::Rc<dyn ExampleTrait + 'a> where Self: 'a
+///   # { unimplemented!() }
+///   # }
+///   ```
+///   for sharing the trait object within a thread.
+/// - `bound`: for each generic type parameter of the enum that is used as the type of a variant's first field,
+///   adds a `where T: Trait` bound to the generated impl instead of requiring it on the enum definition.
+/// - `iter`: the first fields are collections of values implementing the traits, such as `Vec<T>`, arrays or `&mut [T]`,
+///   with `iter` and `iter_mut` methods. Instead of the methods above, generates
+///   ```
+///   # trait ExampleTrait {}
+///   # struct S;
+///   # impl S {
+///   fn iter_dyn_example_trait(&self) -> impl Iterator<Item = &dyn ExampleTrait> + '_
+///   # { None.into_iter() }
+///   fn iter_dyn_example_trait_mut(&mut self) -> impl Iterator<Item = &mut dyn ExampleTrait> + '_
+///   # { None.into_iter() }
+///   # }
+///   ```
+///   The iterators are boxed internally to unify the different collection types.
+///   Not supported with `arc`, `rc`, `slice` or `lock`.
+/// - `slice`: additionally generates
+///   ```
+///   # trait ExampleTrait {}
+///   # struct S;
+///   # impl S {
+///   fn as_dyn_example_trait_slice(slice: &[Self]) -> Vec<&dyn ExampleTrait>
+///   # { unimplemented!() }
+///   # }
+///   ```
+///   which converts every value in a slice of the enum into a trait object.
+/// - `lock`: the first fields are [`std::sync::RwLock`]s of values implementing the traits.
+///   Instead of the methods above, generates
+///   ```
+///   # use std::ops::{Deref, DerefMut};
+///   # trait ExampleTrait {}
+///   # struct S;
+///   # impl S {
+///   fn read_dyn_example_trait<'a>(&'a self) -> impl Deref<Target = dyn ExampleTrait + 'a> + 'a
+///   # { unimplemented!() as Box<dyn ExampleTrait> }
+///   fn write_dyn_example_trait<'a>(&'a self) -> impl DerefMut<Target = dyn ExampleTrait + 'a> + 'a
+///   # { unimplemented!() as Box<dyn ExampleTrait> }
+///   # }
+///   ```
+///   which lock the field for reading or writing and panic if the lock is poisoned.
+///   Not supported with `arc`, `rc`, `slice` or `iter`.
+///   The standard library's guards can't be mapped to a trait object, so the guards are boxed internally
+///   instead of using the mappable guards of crates like `parking_lot`.
+///   The trait objects live as long as the borrow of the enum, so the values in the locks may borrow data.
+/// - `alloc`: the generated code refers to `Box`, `Vec`, `Rc` and `Arc` through the `alloc` crate instead of `std`,
+///   for use in `no_std` crates that declare `extern crate alloc;` at the crate root.
 ///
 /// # Example
 /// ```
@@ -93,10 +404,13 @@ pub fn with_methods(args: TokenStream, input: TokenStream) -> TokenStream {
 ///             Self::File { file, .. } => file as &mut dyn Write,
 ///         }
 ///     }
-///     fn into_dyn_write(self) -> Box<dyn Write> {
+///     fn into_dyn_write<'a>(self) -> Box<dyn Write + 'a>
+///     where
+///         Self: 'a,
+///     {
 ///         match self {
-///             Self::Cursor(first, ..) => Box::new(first) as Box<dyn Write>,
-///             Self::File { file, .. } => Box::new(file) as Box<dyn Write>,
+///             Self::Cursor(first, ..) => Box::new(first) as Box<dyn Write + 'a>,
+///             Self::File { file, .. } => Box::new(file) as Box<dyn Write + 'a>,
 ///         }
 ///     }
 /// }
@@ -107,6 +421,643 @@ pub fn as_dyn(args: TokenStream, input: TokenStream) -> TokenStream {
     as_dyn::as_dyn_impl(args, input)
 }
 
+/// Generates methods for an enum that match on the enum
+/// and return a reference to the variant's first field converted with [`AsRef`].
+///
+/// Takes a comma-separated list of target types as an argument.
+/// The name of the target type is snake_cased for the method names:
+/// paths use their last segment followed by the names of their generic type arguments,
+/// slices are prefixed with `slice` and arrays with `array`.
+/// For example, for the targets `str`, `Vec<u8>` and `[u8]` it would generate
+/// ```
+/// # struct S;
+/// # impl S {
+/// fn as_ref_str(&self) -> &str
+/// # { unimplemented!() }
+/// fn as_ref_vec_u8(&self) -> &Vec<u8>
+/// # { unimplemented!() }
+/// fn as_ref_slice_u8(&self) -> &[u8]
+/// # { unimplemented!() }
+/// # }
+/// ```
+///
+/// Passing `cfg = predicate` as an argument wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// The `as_mut` flag also generates methods like `fn as_mut_str(&mut self) -> &mut str` using [`AsMut`].
+/// The `trait_impls` flag implements [`AsRef`] (and [`AsMut`]) for the enum instead of generating methods,
+/// so that it can be passed to functions taking `impl AsRef<str>` for example.
+///
+/// # Example
+/// ```
+/// #[impl_enum::as_ref(str, [u8])]
+/// enum Name {
+///     Owned(String),
+///     Static { name: &'static str },
+/// }
+///
+/// let name = Name::Owned("owned".to_string());
+/// assert_eq!("owned", name.as_ref_str());
+/// let name = Name::Static { name: "static" };
+/// assert_eq!(b"static", name.as_ref_slice_u8());
+/// ```
+#[cfg(feature = "as_ref")]
+#[proc_macro_attribute]
+pub fn as_ref(args: TokenStream, input: TokenStream) -> TokenStream {
+    as_ref::as_ref_impl(args, input)
+}
+
+/// Generates methods for an enum that match on the enum
+/// and return a mutable reference to the variant's first field converted with [`AsMut`].
+///
+/// Takes the same arguments as [macro@as_ref], generating methods like `fn as_mut_str(&mut self) -> &mut str`
+/// or implementing [`AsMut`] for the enum with the `trait_impls` flag.
+///
+/// # Example
+/// ```
+/// #[impl_enum::as_mut(str)]
+/// enum Name {
+///     Owned(String),
+///     Boxed { name: Box<str> },
+/// }
+///
+/// let mut name = Name::Owned("owned".to_string());
+/// name.as_mut_str().make_ascii_uppercase();
+/// assert!(matches!(name, Name::Owned(s) if s == "OWNED"));
+/// ```
+#[cfg(feature = "as_mut")]
+#[proc_macro_attribute]
+pub fn as_mut(args: TokenStream, input: TokenStream) -> TokenStream {
+    as_mut::as_mut_impl(args, input)
+}
+
+/// Generates constants and methods for converting between an enum's variants and their names.
+///
+/// Generates
+/// ```
+/// # struct S;
+/// # impl S {
+/// const VARIANT_NAMES: &'static [&'static str] = &[/* .. */];
+/// const VARIANTS: &'static [&'static str] = Self::VARIANT_NAMES;
+/// fn variant_name(&self) -> &'static str
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum. The names are listed in the order the variants are declared in,
+/// leaving out the variants removed by a `#[cfg]` attribute. `VARIANTS` is the same list
+/// under the name used by other crates, for building command line help or configuration schemas for example.
+///
+/// If the `from_name` flag is passed as an argument, additionally generates
+/// ```
+/// # struct Field;
+/// # struct S;
+/// # impl S {
+/// fn from_name(name: &str, value: Field) -> Option<Self>
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// which constructs the variant with the given name, or returns `None` if there is no such variant.
+/// This requires every variant to have exactly one field, and all of the fields to have the same type.
+///
+/// Passing `cfg = predicate` as an argument wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// # Example
+/// ```
+/// #[impl_enum::variant_names(from_name)]
+/// #[derive(Debug, PartialEq)]
+/// enum Level {
+///     Low(u8),
+///     High { value: u8 },
+/// }
+///
+/// assert_eq!(&["Low", "High"], Level::VARIANT_NAMES);
+/// let high = Level::High { value: 200 };
+/// assert_eq!("High", high.variant_name());
+/// assert_eq!(Some(high), Level::from_name("High", 200));
+/// assert_eq!(None, Level::from_name("Medium", 100));
+/// ```
+#[cfg(feature = "variant_names")]
+#[proc_macro_attribute]
+pub fn variant_names(args: TokenStream, input: TokenStream) -> TokenStream {
+    variant_names::variant_names_impl(args, input)
+}
+
+/// Generates a predicate method for each of an enum's variants that checks whether the value is of that variant.
+///
+/// The name of the variant is snake_cased for the method names,
+/// so that for the variants `Cursor` and `TcpStream` it would generate
+/// ```
+/// # struct S;
+/// # impl S {
+/// fn is_cursor(&self) -> bool
+/// # { unimplemented!() }
+/// fn is_tcp_stream(&self) -> bool
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum. Unit, tuple and struct variants are all supported.
+///
+/// For variants with fields, also generates accessors like
+/// ```
+/// # use std::io::Cursor;
+/// # struct S;
+/// # impl S {
+/// fn as_cursor(&self) -> Option<&Cursor<Vec<u8>>>
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// that return a reference to the field if the value is of that variant, and
+/// ```
+/// # use std::io::Cursor;
+/// # struct S;
+/// # impl S {
+/// fn into_cursor(self) -> Option<Cursor<Vec<u8>>>
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// that move the field out of the value. The value is dropped if it's of another variant,
+/// [macro@try_into_variants] can be used to get it back instead.
+/// Variants with multiple fields return a tuple of all of the fields,
+/// unless a field is marked with `#[impl_enum(delegate)]` or `#[impl_enum(to)]`,
+/// or the variant is annotated with `#[impl_enum(to = name)]`, in which case only that field is returned.
+///
+/// Passing `cfg = predicate` as an argument wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// # Example
+/// ```
+/// #[impl_enum::variant_helpers]
+/// enum Level {
+///     Low(u8),
+///     High { value: u8, label: &'static str },
+///     Off,
+/// }
+///
+/// assert!(Level::Low(1).is_low());
+/// assert!(!Level::High { value: 200, label: "high" }.is_low());
+/// assert!(Level::Off.is_off());
+/// assert_eq!(Some(&1), Level::Low(1).as_low());
+/// assert_eq!(Some((&200, &"high")), Level::High { value: 200, label: "high" }.as_high());
+/// assert_eq!(None, Level::Off.as_low());
+/// assert_eq!(Some(200), Level::High { value: 200, label: "high" }.into_high().map(|(value, _)| value));
+/// ```
+#[cfg(feature = "variant_helpers")]
+#[proc_macro_attribute]
+pub fn variant_helpers(args: TokenStream, input: TokenStream) -> TokenStream {
+    variant_helpers::variant_helpers_impl(args, input)
+}
+
+/// Generates a method for an enum whose variants all have the same first field type
+/// that moves the variant's first field out of the enum, or returns the default value for unit variants.
+///
+/// Generates
+/// ```
+/// # struct Field;
+/// # struct S;
+/// # impl S {
+/// fn take_or_default(self) -> Field
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum.
+/// The field type must implement [`Default`].
+///
+/// Passing `cfg = predicate` as an argument wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// # Example
+/// ```
+/// #[impl_enum::take_or_default]
+/// enum Buffer {
+///     Empty,
+///     Read(Vec<u8>),
+///     Written { buf: Vec<u8>, len: usize },
+/// }
+///
+/// assert_eq!(Vec::<u8>::new(), Buffer::Empty.take_or_default());
+/// assert_eq!(vec![1, 2], Buffer::Read(vec![1, 2]).take_or_default());
+/// ```
+#[cfg(feature = "take_or_default")]
+#[proc_macro_attribute]
+pub fn take_or_default(args: TokenStream, input: TokenStream) -> TokenStream {
+    take_or_default::take_or_default_impl(args, input)
+}
+
+/// Generates a method that converts the enum into another enum with correspondingly named variants.
+///
+/// Takes the path of the target enum as an argument.
+/// The name of the target enum is snake_cased for the method name.
+/// For example, for the target enum `NewConfig` it would generate
+/// ```
+/// # struct NewConfig;
+/// # struct S;
+/// # impl S {
+/// fn to_new_config(self) -> NewConfig
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum.
+///
+/// Every variant must have exactly one field, which is moved into the variant of the target enum with the same name.
+/// The corresponding variants must either both be tuple variants or have the same field name.
+/// A variant without a corresponding variant in the target enum results in a compilation error.
+///
+/// Passing `cfg = predicate` after the target enum wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// # Example
+/// ```
+/// #[derive(Debug, PartialEq)]
+/// enum New {
+///     Text(String),
+///     Number { value: i64 },
+///     Flag(bool),
+/// }
+///
+/// #[impl_enum::map_to(New)]
+/// enum Old {
+///     Text(String),
+///     Number { value: i64 },
+/// }
+///
+/// assert_eq!(New::Text("text".to_string()), Old::Text("text".to_string()).to_new());
+/// assert_eq!(New::Number { value: 1 }, Old::Number { value: 1 }.to_new());
+/// ```
+#[cfg(feature = "map_to")]
+#[proc_macro_attribute]
+pub fn map_to(args: TokenStream, input: TokenStream) -> TokenStream {
+    map_to::map_to_impl(args, input)
+}
+
+/// Generates an implementation of [`std::io::Write`] for an enum
+/// that matches on the enum and forwards each method to the variant's first field.
+///
+/// The forwarded methods are `write`, `write_vectored`, `flush`, `write_all` and `write_fmt`,
+/// so that the specialized implementations of the field types are used.
+/// The other methods of the trait use their default implementations.
+/// The field types must implement [`std::io::Write`],
+/// with any bounds on generic parameters declared on the enum.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Write};
+///
+/// #[impl_enum::impl_io_write]
+/// enum Output {
+///     Vec(Vec<u8>),
+///     Cursor { cursor: Cursor<Vec<u8>> },
+/// }
+///
+/// let mut output = Output::Vec(vec![]);
+/// write!(output, "{}", 1).unwrap();
+/// output.write_all(b"23").unwrap();
+/// let dyn_write: &mut dyn Write = &mut output;
+/// dyn_write.flush().unwrap();
+/// ```
+#[cfg(feature = "impl_io_write")]
+#[proc_macro_attribute]
+pub fn impl_io_write(args: TokenStream, input: TokenStream) -> TokenStream {
+    impl_io_write::impl_io_write_impl(args, input)
+}
+
+/// Generates an implementation of [`std::io::Read`] for an enum
+/// that matches on the enum and forwards each method to the variant's first field.
+///
+/// The forwarded methods are `read`, `read_vectored`, `read_to_end`, `read_to_string` and `read_exact`,
+/// so that the specialized implementations of the field types are used.
+/// The other methods of the trait use their default implementations.
+/// The field types must implement [`std::io::Read`],
+/// with any bounds on generic parameters declared on the enum.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Read};
+///
+/// #[impl_enum::impl_io_read]
+/// enum Input<'a> {
+///     Slice(&'a [u8]),
+///     Cursor { cursor: Cursor<Vec<u8>> },
+/// }
+///
+/// let mut input = Input::Slice(b"slice");
+/// let mut string = String::new();
+/// input.read_to_string(&mut string).unwrap();
+/// assert_eq!("slice", string);
+/// ```
+#[cfg(feature = "impl_io_read")]
+#[proc_macro_attribute]
+pub fn impl_io_read(args: TokenStream, input: TokenStream) -> TokenStream {
+    impl_io_read::impl_io_read_impl(args, input)
+}
+
+/// Generates an implementation of [`std::fmt::Display`] for an enum
+/// that matches on the enum and forwards `fmt` to the variant's first field.
+///
+/// The formatter is passed along as is, so flags like width and precision apply to the field.
+/// The field types must implement [`std::fmt::Display`],
+/// with any bounds on generic parameters declared on the enum.
+///
+/// # Example
+/// ```
+/// #[impl_enum::impl_display]
+/// enum Value {
+///     Integer(i64),
+///     Text { text: String },
+/// }
+///
+/// assert_eq!("1", Value::Integer(1).to_string());
+/// assert_eq!("text", format!("{}", Value::Text { text: "text".to_string() }));
+/// ```
+#[cfg(feature = "impl_display")]
+#[proc_macro_attribute]
+pub fn impl_display(args: TokenStream, input: TokenStream) -> TokenStream {
+    impl_display::impl_display_impl(args, input)
+}
+
+/// Generates an implementation of [`Iterator`] for an enum
+/// that matches on the enum and forwards each method to the variant's first field.
+///
+/// Takes the associated item type as an argument in the form `Item = Type`.
+/// The forwarded methods are `next` and `size_hint`, the other methods of the trait use their default implementations.
+/// The field types must implement [`Iterator`] with the given item type,
+/// with any bounds on generic parameters declared on the enum.
+///
+/// # Example
+/// ```
+/// #[impl_enum::impl_iterator(Item = u8)]
+/// enum Bytes {
+///     Vec(std::vec::IntoIter<u8>),
+///     Range { range: std::ops::Range<u8> },
+/// }
+///
+/// assert_eq!(vec![1, 2], Bytes::Vec(vec![1, 2].into_iter()).collect::<Vec<_>>());
+/// assert_eq!(3, Bytes::Range { range: 0..3 }.count());
+/// ```
+#[cfg(feature = "impl_iterator")]
+#[proc_macro_attribute]
+pub fn impl_iterator(args: TokenStream, input: TokenStream) -> TokenStream {
+    impl_iterator::impl_iterator_impl(args, input)
+}
+
+/// Generates methods for an enum whose variants all have the same first field type
+/// that pass a reference to the variant's first field to a closure.
+///
+/// Generates
+/// ```
+/// # struct Field;
+/// # struct S;
+/// # impl S {
+/// fn with_field<R>(&self, f: impl FnOnce(&Field) -> R) -> R
+/// # { unimplemented!() }
+/// fn with_field_mut<R>(&mut self, f: impl FnOnce(&mut Field) -> R) -> R
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum.
+/// Unlike the trait objects of [macro@as_dyn], the closure receives the concrete field type.
+///
+/// Passing `cfg = predicate` as an argument wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// # Example
+/// ```
+/// #[impl_enum::with_field]
+/// enum Counter {
+///     Local(u32),
+///     Remote { count: u32, host: String },
+/// }
+///
+/// let mut counter = Counter::Local(1);
+/// counter.with_field_mut(|count| *count += 1);
+/// assert_eq!(2, counter.with_field(|count| *count));
+/// ```
+#[cfg(feature = "with_field")]
+#[proc_macro_attribute]
+pub fn with_field(args: TokenStream, input: TokenStream) -> TokenStream {
+    with_field::with_field_impl(args, input)
+}
+
+/// Generates a method for an enum that passes a mutable reference to the variant's first field to a closure
+/// as a trait object, for fields of different types that implement the same trait.
+///
+/// Takes the trait as an argument, optionally with additional bounds such as `Write + Send`. Generates
+/// ```
+/// # trait ExampleTrait {}
+/// # struct S;
+/// # impl S {
+/// fn map_inner<R>(&mut self, f: impl FnOnce(&mut dyn ExampleTrait) -> R) -> R
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum. Closures can't be generic over the field type,
+/// so [macro@with_field] should be used instead if all of the fields have the same type.
+///
+/// # Example
+/// ```
+/// use std::io::{Cursor, Write};
+///
+/// #[impl_enum::map_inner(Write)]
+/// enum Writer {
+///     Cursor(Cursor<Vec<u8>>),
+///     Vec { vec: Vec<u8> },
+/// }
+///
+/// let mut writer = Writer::Vec { vec: vec![] };
+/// writer.map_inner(|w| w.write_all(b"vec")).unwrap();
+/// assert!(matches!(writer, Writer::Vec { vec } if vec == b"vec"));
+/// ```
+#[cfg(feature = "map_inner")]
+#[proc_macro_attribute]
+pub fn map_inner(args: TokenStream, input: TokenStream) -> TokenStream {
+    map_inner::map_inner_impl(args, input)
+}
+
+/// Generates a constructor for an enum that parses a string into the first variant whose field type can parse it.
+///
+/// Generates
+/// ```
+/// # struct S;
+/// # impl S {
+/// fn try_parse(s: &str) -> Option<Self>
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum.
+/// Every variant must have exactly one field, whose type must implement [`std::str::FromStr`].
+/// The field types are tried in the order the variants are defined in, so a string that several of them can parse
+/// is parsed into the first such variant. `None` is returned if none of them can parse the string.
+///
+/// # Example
+/// ```
+/// #[impl_enum::try_parse]
+/// #[derive(Debug, PartialEq)]
+/// enum Value {
+///     Integer(i64),
+///     Float { float: f64 },
+///     Text(String),
+/// }
+///
+/// assert_eq!(Some(Value::Integer(1)), Value::try_parse("1"));
+/// assert_eq!(Some(Value::Float { float: 1.5 }), Value::try_parse("1.5"));
+/// assert_eq!(Some(Value::Text("one".to_string())), Value::try_parse("one"));
+/// ```
+#[cfg(feature = "try_parse")]
+#[proc_macro_attribute]
+pub fn try_parse(args: TokenStream, input: TokenStream) -> TokenStream {
+    try_parse::try_parse_impl(args, input)
+}
+
+/// Generates methods for an enum whose variants all have the same first field type
+/// that return the variant's first field.
+///
+/// Generates
+/// ```
+/// # struct Field;
+/// # struct S;
+/// # impl S {
+/// fn inner(&self) -> &Field
+/// # { unimplemented!() }
+/// fn inner_mut(&mut self) -> &mut Field
+/// # { unimplemented!() }
+/// fn into_inner(self) -> Field
+/// # { unimplemented!() }
+/// # }
+/// ```
+/// with the same visibility as the enum.
+/// The field type may be a generic parameter of the enum, in which case the methods return the parameter type
+/// instead of a trait object.
+///
+/// Passing `cfg = predicate` as an argument wraps the generated impl in `#[cfg(predicate)]`,
+/// for example `cfg = test` for accessors that are only used in tests.
+///
+/// # Example
+/// ```
+/// #[impl_enum::inner_ref]
+/// enum Versioned<T> {
+///     Draft(T),
+///     Published { value: T, version: u32 },
+/// }
+///
+/// let mut versioned = Versioned::Draft(vec![1]);
+/// versioned.inner_mut().push(2);
+/// assert_eq!(&[1, 2], versioned.inner().as_slice());
+/// assert_eq!(vec![1, 2], versioned.into_inner());
+/// ```
+#[cfg(feature = "inner_ref")]
+#[proc_macro_attribute]
+pub fn inner_ref(args: TokenStream, input: TokenStream) -> TokenStream {
+    inner_ref::inner_ref_impl(args, input)
+}
+
+/// Implements a trait for an enum by delegating its methods to the variant's first field.
+///
+/// Takes the trait followed by the signatures of the methods to delegate in braces,
+/// because a macro can't see the definition of the trait from its path.
+/// The signatures can be copied from the trait definition, with or without semicolons,
+/// and every required method of the trait must be listed.
+/// Methods with a default implementation can be left out to use the default.
+///
+/// This is a shorthand for the `trait_impls` option of [`macro@with_methods`],
+/// so the signatures support the same options and the variants support the same helper attributes.
+///
+/// # Example
+/// ```
+/// use std::fmt::Write;
+///
+/// #[impl_enum::delegate_trait(Write {
+///     fn write_str(&mut self, s: &str) -> std::fmt::Result;
+///     fn write_char(&mut self, c: char) -> std::fmt::Result;
+/// })]
+/// enum Buffer {
+///     String(String),
+///     Named { string: String, name: &'static str },
+/// }
+///
+/// let mut buffer = Buffer::String(String::new());
+/// write!(buffer, "{}-{}", 1, 2).unwrap();
+/// ```
+#[cfg(feature = "delegate_trait")]
+#[proc_macro_attribute]
+pub fn delegate_trait(args: TokenStream, input: TokenStream) -> TokenStream {
+    delegate_trait::delegate_trait_impl(args, input)
+}
+
+/// Implements [`From`] for the enum from the field type of each variant,
+/// wrapping the value in the variant.
+///
+/// Every variant must have exactly one field. A variant can be annotated with `#[impl_enum(skip)]`
+/// to leave it out, for example when another variant has the same field type.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+///
+/// #[impl_enum::from_variants]
+/// enum Writer {
+///     Cursor(Cursor<Vec<u8>>),
+///     Vec { vec: Vec<u8> },
+///     #[impl_enum(skip)]
+///     Sink,
+/// }
+///
+/// let writer: Writer = Cursor::new(vec![]).into();
+/// assert!(matches!(writer, Writer::Cursor(_)));
+/// assert!(matches!(Writer::from(vec![1]), Writer::Vec { .. }));
+/// ```
+#[cfg(feature = "from_variants")]
+#[proc_macro_attribute]
+pub fn from_variants(args: TokenStream, input: TokenStream) -> TokenStream {
+    from_variants::from_variants_impl(args, input)
+}
+
+/// Implements [`TryFrom`] for the field type of each variant from the enum,
+/// returning the field if the value is of that variant and the value itself otherwise.
+///
+/// Every variant must have exactly one field. A variant can be annotated with `#[impl_enum(skip)]`
+/// to leave it out, for example when another variant has the same field type.
+/// Due to the orphan rules, variants whose field is a bare type parameter of the enum need to be skipped.
+///
+/// # Example
+/// ```
+/// use std::{convert::TryFrom, io::Cursor};
+///
+/// #[impl_enum::try_into_variants]
+/// #[derive(Debug)]
+/// enum Writer {
+///     Cursor(Cursor<Vec<u8>>),
+///     Vec { vec: Vec<u8> },
+/// }
+///
+/// let cursor = Cursor::<Vec<u8>>::try_from(Writer::Cursor(Cursor::new(vec![1]))).unwrap();
+/// assert_eq!(&[1], cursor.get_ref().as_slice());
+/// let writer = Cursor::<Vec<u8>>::try_from(Writer::Vec { vec: vec![] }).unwrap_err();
+/// assert!(matches!(writer, Writer::Vec { .. }));
+/// ```
+#[cfg(feature = "try_into_variants")]
+#[proc_macro_attribute]
+pub fn try_into_variants(args: TokenStream, input: TokenStream) -> TokenStream {
+    try_into_variants::try_into_variants_impl(args, input)
+}
+
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
 fn first_field(variant: &Variant) -> syn::Result<&Field> {
     match &variant.fields {
         Fields::Named(fields) => fields.named.first(),
@@ -114,14 +1065,702 @@ fn first_field(variant: &Variant) -> syn::Result<&Field> {
         Fields::Unit => {
             return Err(Error::new(
                 variant.span(),
-                "Unit variants are not supported",
+                format!("The unit variant `{}` is not supported", variant.ident),
             ))
         }
     }
     .ok_or_else(|| {
         Error::new(
-            variant.fields.span(),
-            "Enum variants must have at least one field",
+            variant.span(),
+            format!(
+                "The variant `{}` must have at least one field",
+                variant.ident
+            ),
+        )
+    })
+}
+
+/// Makes an expression that matches the value against the patterns of the arms and evaluates the matching arm.
+/// An enum with a single variant is destructured with a `let` statement instead of a `match`,
+/// unless the pattern starts with the `#[cfg]` attributes of the variant, which are only allowed on match arms.
+/// An enum without variants is matched through a reference, which works for values and references alike,
+/// because references to uninhabited types are not considered uninhabited.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "inner_ref",
+    feature = "try_into_variants",
+))]
+fn make_match(value: &TokenStream2, arms: &[(TokenStream2, TokenStream2)]) -> TokenStream2 {
+    if arms.is_empty() {
+        return quote::quote! {{
+            let __never: &Self = &#value;
+            match *__never {}
+        }};
+    }
+    if let [(pattern, expr)] = arms {
+        let has_attrs = matches!(
+            pattern.clone().into_iter().next(),
+            Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == '#'
+        );
+        if !has_attrs {
+            return quote::quote! {{
+                let #pattern = #value;
+                #expr
+            }};
+        }
+    }
+    let arms = arms
+        .iter()
+        .map(|(pattern, expr)| quote::quote!(#pattern => #expr));
+    quote::quote! {
+        match #value {
+            #(#arms),*
+        }
+    }
+}
+
+/// Makes an expression that matches on `self` with an arm for every variant
+/// that binds the delegate field of the variant to `__first` and evaluates the expression.
+#[cfg(any(
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "inner_ref",
+))]
+fn make_delegate_match(input_enum: &ItemEnum, expr: &TokenStream2) -> syn::Result<TokenStream2> {
+    let arms = input_enum
+        .variants
+        .iter()
+        .map(|variant| make_delegate_arm(variant, expr.clone()))
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(make_match(&quote::quote!(self), &arms))
+}
+
+/// Makes a match arm for the variant that binds its delegate field to `__first` and evaluates the expression.
+/// The arm has the `#[cfg]` attributes of the variant and allows its use if it's deprecated.
+#[cfg(any(
+    feature = "as_dyn",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "inner_ref",
+))]
+fn make_delegate_arm(
+    variant: &Variant,
+    expr: TokenStream2,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let (first_member, _) = delegate_field(variant, None)?;
+    let variant_ident = &variant.ident;
+    let cfg = cfg_attrs(variant);
+    let allow_deprecated = allow_deprecated(variant);
+    Ok((
+        quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #first_member: __first, .. }),
+        expr,
+    ))
+}
+
+/// Returns the `#[cfg]` attributes of the variant,
+/// to be attached to the code generated for it so that the code is removed along with the variant.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn cfg_attrs(variant: &Variant) -> TokenStream2 {
+    let cfgs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"));
+    quote::quote!(#(#cfgs)*)
+}
+
+/// Returns the members used to access each of the fields.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn members(fields: &Fields) -> Vec<Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect()
+}
+
+/// Returns the member and field of the variant to delegate to, which is the field marked with
+/// `#[impl_enum(delegate)]` or `#[impl_enum(to)]`, the field named by `#[impl_enum(to = name)]` on the variant,
+/// the field with the given member or the first field, in that order.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "take_or_default",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn delegate_field<'a>(
+    variant: &'a Variant,
+    member: Option<&Member>,
+) -> syn::Result<(Member, &'a Field)> {
+    use quote::ToTokens;
+
+    if let Some(marked) = marked_field(variant)? {
+        return Ok(marked);
+    }
+    if let Some(named) = named_field(variant)? {
+        return Ok(named);
+    }
+    let member = match member {
+        Some(member) => member,
+        None => {
+            let first_field = first_field(variant)?;
+            let first_member = members(&variant.fields).remove(0);
+            return Ok((first_member, first_field));
+        }
+    };
+    // an index selects the field at that position in named variants as well
+    if let Member::Unnamed(index) = member {
+        let position = index.index as usize;
+        return members(&variant.fields)
+            .into_iter()
+            .zip(&variant.fields)
+            .nth(position)
+            .ok_or_else(|| {
+                Error::new(
+                    variant.span(),
+                    format!(
+                        "The variant `{}` has {} field(s), so there is no field at index {}",
+                        variant.ident,
+                        variant.fields.len(),
+                        position
+                    ),
+                )
+            });
+    }
+    members(&variant.fields)
+        .into_iter()
+        .zip(&variant.fields)
+        .find(|(m, _)| m == member)
+        .ok_or_else(|| {
+            Error::new(
+                member.span(),
+                format!(
+                    "The variant `{}` has no field `{}`",
+                    variant.ident,
+                    member.to_token_stream()
+                ),
+            )
+        })
+}
+
+/// Returns the member and field of the variant marked with `#[impl_enum(delegate)]`, if any.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn marked_field(variant: &Variant) -> syn::Result<Option<(Member, &Field)>> {
+    let mut marked = None;
+    for (member, field) in members(&variant.fields).into_iter().zip(&variant.fields) {
+        for attr in field.attrs.iter().filter(|attr| is_helper_attr(attr)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("delegate") || meta.path.is_ident("to") {
+                    Ok(())
+                } else {
+                    Err(meta.error("Unrecognized option"))
+                }
+            })?;
+            if marked.is_some() {
+                return Err(Error::new(
+                    attr.span(),
+                    format!(
+                        "Only one field of the variant `{}` can be marked as the delegate",
+                        variant.ident
+                    ),
+                ));
+            }
+            marked = Some((member.clone(), field));
+        }
+    }
+    Ok(marked)
+}
+
+/// Returns the member and field of the variant named with `#[impl_enum(to = name)]` on the variant, if any.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn named_field(variant: &Variant) -> syn::Result<Option<(Member, &Field)>> {
+    use quote::ToTokens;
+
+    let mut named = None;
+    for attr in variant.attrs.iter().filter(|attr| is_helper_attr(attr)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("to") {
+                let member: Member = meta.value()?.parse()?;
+                let field = members(&variant.fields)
+                    .into_iter()
+                    .zip(&variant.fields)
+                    .find(|(m, _)| m == &member);
+                match field {
+                    Some(field) => named = Some(field),
+                    None => {
+                        let available = members(&variant.fields)
+                            .iter()
+                            .map(|m| format!("`{}`", m.to_token_stream()))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        return Err(Error::new(
+                            attr.span(),
+                            format!(
+                                "The variant `{}` has no field `{}`, the available fields are {}",
+                                variant.ident,
+                                member.to_token_stream(),
+                                available
+                            ),
+                        ));
+                    }
+                }
+            } else if meta.input.peek(Token![=]) {
+                // the other options are handled by the macros
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(named)
+}
+
+/// Checks whether the variant is annotated with `#[impl_enum(skip)]`.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn is_skipped(variant: &Variant) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in variant.attrs.iter().filter(|attr| is_helper_attr(attr)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skipped = true;
+            } else if meta.input.peek(Token![=]) {
+                // the other options are handled by the macros
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(skipped)
+}
+
+/// Makes a match arm that panics with a message naming the skipped variant and the method.
+#[cfg(any(feature = "with_methods", feature = "as_dyn"))]
+fn skipped_arm(variant: &Variant, method: &syn::Ident) -> (TokenStream2, TokenStream2) {
+    use syn::ext::IdentExt;
+
+    let variant_ident = &variant.ident;
+    let cfg = cfg_attrs(variant);
+    let allow_deprecated = allow_deprecated(variant);
+    let message = format!(
+        "`{}` was called on the skipped variant `{}`",
+        method.unraw(),
+        variant_ident.unraw(),
+    );
+    (
+        quote::quote!(#cfg #allow_deprecated Self::#variant_ident { .. }),
+        quote::quote!(::core::panic!(#message)),
+    )
+}
+
+/// Makes a `# Panics` section for the documentation of a method that panics on the skipped variants.
+#[cfg(any(feature = "with_methods", feature = "as_dyn"))]
+fn skipped_panics_doc(input_enum: &ItemEnum) -> syn::Result<Option<TokenStream2>> {
+    use syn::ext::IdentExt;
+
+    let mut skipped = vec![];
+    for variant in &input_enum.variants {
+        if is_skipped(variant)? {
+            skipped.push(format!("`{}`", variant.ident.unraw()));
+        }
+    }
+    if skipped.is_empty() {
+        return Ok(None);
+    }
+    let message = format!(
+        "Panics if called on the skipped variants {}.",
+        skipped.join(", ")
+    );
+    Ok(Some(quote::quote! {
+        #[doc = ""]
+        #[doc = "# Panics"]
+        #[doc = ""]
+        #[doc = #message]
+    }))
+}
+
+/// Returns the only field of the variant.
+#[cfg(any(
+    feature = "variant_names",
+    feature = "map_to",
+    feature = "try_parse",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn only_field(variant: &Variant) -> syn::Result<&Field> {
+    let field = first_field(variant)?;
+    if variant.fields.len() > 1 {
+        return Err(Error::new(
+            variant.span(),
+            format!(
+                "The variant `{}` must have exactly one field",
+                variant.ident
+            ),
+        ));
+    }
+    Ok(field)
+}
+
+/// Returns the type of the delegate field shared by all of the variants, ignoring unit variants.
+#[cfg(any(
+    feature = "variant_names",
+    feature = "take_or_default",
+    feature = "with_field",
+    feature = "inner_ref",
+))]
+fn common_field_type(input_enum: &ItemEnum) -> syn::Result<&syn::Type> {
+    use quote::ToTokens;
+
+    let mut common_type: Option<&syn::Type> = None;
+    for variant in &input_enum.variants {
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
+        let (_, field) = delegate_field(variant, None)?;
+        let field_type = &field.ty;
+        match common_type {
+            Some(common_type) => {
+                if common_type.to_token_stream().to_string()
+                    != field_type.to_token_stream().to_string()
+                {
+                    return Err(Error::new(
+                        field_type.span(),
+                        format!(
+                            "The field types of the variants must be the same, expected `{}`",
+                            common_type.to_token_stream()
+                        ),
+                    ));
+                }
+            }
+            None => common_type = Some(field_type),
+        }
+    }
+    common_type.ok_or_else(|| {
+        Error::new(
+            input_enum.ident.span(),
+            "The enum must have at least one variant with fields",
         )
     })
 }
+
+/// Parses the arguments of a macro, prefixing the message of a generic parse error from syn,
+/// such as "expected `,`", with a description of the expected arguments.
+/// The error is reported at the first unexpected token rather than the whole attribute.
+#[cfg(any(feature = "with_methods", feature = "as_dyn",))]
+fn parse_args<T: syn::parse::Parse>(arg: TokenStream2, expected: &str) -> syn::Result<T> {
+    syn::parse2::<T>(arg).map_err(|err| {
+        let message = err.to_string();
+        if message.starts_with("expected") || message.starts_with("unexpected") {
+            Error::new(err.span(), format!("{expected}: {message}"))
+        } else {
+            err
+        }
+    })
+}
+
+/// Parses a `cfg = predicate` option used to conditionally compile the generated impl.
+#[cfg(any(
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "variant_helpers",
+    feature = "with_field",
+    feature = "inner_ref",
+))]
+fn parse_cfg_option(input: syn::parse::ParseStream) -> syn::Result<Option<syn::Meta>> {
+    let fork = input.fork();
+    match fork.parse::<syn::Ident>() {
+        Ok(ident) if ident == "cfg" && fork.peek(Token![=]) => {}
+        _ => return Ok(None),
+    }
+    input.parse::<syn::Ident>()?;
+    input.parse::<Token![=]>()?;
+    Ok(Some(input.parse()?))
+}
+
+/// Derives a snake_case name for a type to be used in method names.
+///
+/// Paths use their last segment followed by the names of their generic type arguments,
+/// slices are prefixed with `slice` and arrays with `array`.
+/// For example, `str` becomes `str`, `Vec<u8>` becomes `vec_u8` and `[u8]` becomes `slice_u8`.
+#[cfg(any(feature = "as_dyn", feature = "as_ref",))]
+fn type_name(ty: &syn::Type) -> syn::Result<String> {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => path_name(&type_path.path),
+        syn::Type::Slice(slice) => Ok(format!("slice_{}", type_name(&slice.elem)?)),
+        syn::Type::Array(array) => Ok(format!("array_{}", type_name(&array.elem)?)),
+        syn::Type::Paren(paren) => type_name(&paren.elem),
+        syn::Type::Group(group) => type_name(&group.elem),
+        _ => Err(Error::new(
+            ty.span(),
+            "Cannot derive a method name for this type",
+        )),
+    }
+}
+
+/// Derives a snake_case name for a path from its last segment followed by the names of its generic type arguments.
+#[cfg(any(feature = "as_dyn", feature = "as_ref",))]
+fn path_name(path: &syn::Path) -> syn::Result<String> {
+    use heck::ToSnakeCase;
+    use syn::ext::IdentExt;
+
+    let segment = path.segments.last().expect("empty path");
+    let mut name = segment.ident.unraw().to_string().to_snake_case();
+    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+        for arg in &args.args {
+            if let syn::GenericArgument::Type(arg) = arg {
+                name.push('_');
+                name.push_str(&type_name(arg)?);
+            }
+        }
+    }
+    Ok(name)
+}
+
+/// Returns an attribute that allows the use of the variant if it's deprecated,
+/// to be attached to the match arms that name the variant,
+/// so that deprecation warnings about the code the arms call are not silenced for the other variants.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "inner_ref",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "try_parse",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn allow_deprecated(variant: &Variant) -> Option<TokenStream2> {
+    if variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("deprecated"))
+    {
+        Some(quote::quote!(#[allow(deprecated)]))
+    } else {
+        None
+    }
+}
+
+/// Checks whether the attribute is a helper attribute for the macros in this crate.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn is_helper_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident("impl_enum")
+}
+
+/// The names of the attribute macros in this crate.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+const MACROS: &[&str] = &[
+    "with_methods",
+    "as_dyn",
+    "as_ref",
+    "as_mut",
+    "variant_names",
+    "variant_helpers",
+    "take_or_default",
+    "map_to",
+    "impl_io_write",
+    "impl_io_read",
+    "impl_display",
+    "impl_iterator",
+    "with_field",
+    "map_inner",
+    "try_parse",
+    "inner_ref",
+    "delegate_trait",
+    "from_variants",
+    "try_into_variants",
+];
+
+/// Removes the helper attributes from the variants and fields of the enum
+/// so that the compiler doesn't try to resolve them after expansion.
+/// They are kept if another macro from this crate is yet to be expanded on the enum.
+#[cfg(any(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "impl_io_write",
+    feature = "impl_io_read",
+    feature = "impl_display",
+    feature = "impl_iterator",
+    feature = "with_field",
+    feature = "map_inner",
+    feature = "try_parse",
+    feature = "inner_ref",
+    feature = "from_variants",
+    feature = "try_into_variants",
+))]
+fn strip_helper_attrs(input_enum: &mut ItemEnum) {
+    let pending_macro = input_enum.attrs.iter().any(|attr| {
+        let segments = &attr.path().segments;
+        segments.first().map_or(false, |s| s.ident == "impl_enum")
+            || segments
+                .last()
+                .map_or(false, |s| MACROS.iter().any(|m| s.ident == m))
+    });
+    if pending_macro {
+        return;
+    }
+    for variant in &mut input_enum.variants {
+        variant.attrs.retain(|attr| !is_helper_attr(attr));
+        for field in &mut variant.fields {
+            field.attrs.retain(|attr| !is_helper_attr(attr));
+        }
+    }
+}
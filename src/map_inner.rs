@@ -0,0 +1,65 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, ItemEnum, Path, Token,
+};
+
+pub fn map_inner_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    /// The trait implemented by the fields, along with any additional bounds such as `Send`.
+    bounds: Punctuated<Path, Token![+]>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let bounds = Punctuated::parse_separated_nonempty(input)?;
+        Ok(Args { bounds })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    // a trait object with multiple bounds is parenthesized to be used behind references
+    let bounds = &args.bounds;
+    let dyn_path = if bounds.len() > 1 {
+        quote::quote!((dyn #bounds))
+    } else {
+        quote::quote!(dyn #bounds)
+    };
+
+    // the closure can't be generic so it receives a trait object
+    let field_match =
+        super::make_delegate_match(input_enum, &quote::quote!(f(__first as &mut #dyn_path)))?;
+
+    // construct the impl
+    let vis = &input_enum.vis;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis fn map_inner<__R>(&mut self, f: impl ::core::ops::FnOnce(&mut #dyn_path) -> __R) -> __R {
+                #field_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
@@ -0,0 +1,101 @@
+use heck::ToSnakeCase;
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Error, ItemEnum, Meta, Path, PathArguments, Token,
+};
+
+pub fn map_to_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    target: Path,
+    /// The predicate the impl is conditionally compiled with.
+    cfg: Option<Meta>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let target = input.parse()?;
+        let mut cfg = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            cfg = super::parse_cfg_option(input)?;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("Unrecognized option"));
+        }
+
+        Ok(Args { target, cfg })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let target = &args.target;
+
+    // the generic arguments of the target are inferred from the return type
+    let mut target_constructor = target.clone();
+    for segment in &mut target_constructor.segments {
+        segment.arguments = PathArguments::None;
+    }
+
+    // construct the arms
+    let mut arms = vec![];
+    for variant in &input_enum.variants {
+        super::only_field(variant)?;
+        let member = &super::members(&variant.fields)[0];
+
+        let variant_ident = &variant.ident;
+        let cfg = super::cfg_attrs(variant);
+        let allow_deprecated = super::allow_deprecated(variant);
+        arms.push((
+            quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #member: __first }),
+            quote::quote!(#target_constructor::#variant_ident { #member: __first }),
+        ));
+    }
+    let map_match = super::make_match(&quote::quote!(self), &arms);
+
+    // construct the method name
+    let target_ident = target
+        .segments
+        .last()
+        .expect("empty path")
+        .ident
+        .unraw()
+        .to_string()
+        .to_snake_case();
+    let to_target = Ident::new(&format!("to_{target_ident}"), Span::call_site());
+
+    // construct the impl
+    let vis = &input_enum.vis;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote!(#[cfg(#cfg)]));
+    let enum_impl = quote::quote! {
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis fn #to_target(self) -> #target {
+                #map_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
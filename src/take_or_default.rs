@@ -0,0 +1,123 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, Fields, ItemEnum, Meta, Token,
+};
+
+pub fn take_or_default_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    /// The predicate the impl is conditionally compiled with.
+    cfg: Option<Meta>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let cfg = super::parse_cfg_option(input)?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(input.error("Unrecognized option"));
+        }
+
+        Ok(Args { cfg })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let field_type = super::common_field_type(input_enum)?;
+
+    // construct the arms
+    let mut arms = vec![];
+    for variant in &input_enum.variants {
+        let variant_ident = &variant.ident;
+        let cfg = super::cfg_attrs(variant);
+        let allow_deprecated = super::allow_deprecated(variant);
+        if let Fields::Unit = variant.fields {
+            arms.push((
+                quote::quote!(#cfg #allow_deprecated Self::#variant_ident),
+                quote::quote!(<#field_type as ::core::default::Default>::default()),
+            ));
+            continue;
+        }
+
+        let (first_member, _) = super::delegate_field(variant, None)?;
+        arms.push((
+            quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #first_member: __first, .. }),
+            quote::quote!(__first),
+        ));
+    }
+    let take_match = super::make_match(&quote::quote!(self), &arms);
+
+    // construct the impl
+    let vis = &input_enum.vis;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote!(#[cfg(#cfg)]));
+    let enum_impl = quote::quote! {
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis fn take_or_default(self) -> #field_type {
+                #take_match
+            }
+        }
+    };
+    Ok(enum_impl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An enum without variants has no field type to return.
+    #[test]
+    fn empty_enum() {
+        let input_enum: ItemEnum = syn::parse_quote!(
+            enum Never {}
+        );
+        let err = match make_impl(&Args { cfg: None }, &input_enum) {
+            Ok(_) => panic!("the impl was made"),
+            Err(err) => err,
+        };
+        assert_eq!(
+            "The enum must have at least one variant with fields",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn different_types() {
+        let input_enum: ItemEnum = syn::parse_quote!(
+            enum Slot {
+                Empty,
+                String(String),
+                Vec(Vec<u8>),
+            }
+        );
+        let err = match make_impl(&Args { cfg: None }, &input_enum) {
+            Ok(_) => panic!("the impl was made"),
+            Err(err) => err,
+        };
+        assert_eq!(
+            "The field types of the variants must be the same, expected `String`",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Nothing, ItemEnum};
+
+pub fn try_into_variants_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(arg as Nothing);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let try_from_impls = match make_impls(&input_enum) {
+        Ok(try_from_impls) => try_from_impls,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impls
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #(#try_from_impls)*
+    })
+}
+
+fn make_impls(input_enum: &ItemEnum) -> syn::Result<Vec<TokenStream2>> {
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+
+    // construct an impl for every variant that isn't skipped
+    let mut try_from_impls = vec![];
+    for variant in &input_enum.variants {
+        if super::is_skipped(variant)? {
+            continue;
+        }
+        let field = super::only_field(variant)?;
+        let (member, _) = super::delegate_field(variant, None)?;
+        let variant_ident = &variant.ident;
+        let field_type = &field.ty;
+        let cfg = super::cfg_attrs(variant);
+        // the impl belongs to the variant, so it can use the variant if it's deprecated
+        let allow_deprecated = super::allow_deprecated(variant);
+        // the other variants are returned as the error
+        let arms = [
+            (
+                quote::quote!(#cfg #enum_ident::#variant_ident { #member: __first }),
+                quote::quote!(::core::result::Result::Ok(__first)),
+            ),
+            (
+                quote::quote!(__other),
+                quote::quote!(::core::result::Result::Err(__other)),
+            ),
+        ];
+        let arms = if input_enum.variants.len() == 1 {
+            &arms[..1]
+        } else {
+            &arms[..]
+        };
+        let try_from_match = super::make_match(&quote::quote!(value), arms);
+        try_from_impls.push(quote::quote! {
+            #cfg
+            #allow_deprecated
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<#enum_ident #ty_generics> for #field_type #where_clause {
+                type Error = #enum_ident #ty_generics;
+                fn try_from(value: #enum_ident #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                    #try_from_match
+                }
+            }
+        });
+    }
+    Ok(try_from_impls)
+}
@@ -0,0 +1,57 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Nothing, ItemEnum};
+
+pub fn try_parse_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(arg as Nothing);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+fn make_impl(input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    // try to parse every variant's field in order
+    let mut attempts = vec![];
+    for variant in &input_enum.variants {
+        let field = super::only_field(variant)?;
+        let (member, _) = super::delegate_field(variant, None)?;
+
+        let variant_ident = &variant.ident;
+        let field_type = &field.ty;
+        let cfg = super::cfg_attrs(variant);
+        let allow_deprecated = super::allow_deprecated(variant);
+        attempts.push(quote::quote! {
+            #cfg
+            #allow_deprecated
+            if let ::core::result::Result::Ok(__first) = <#field_type as ::core::str::FromStr>::from_str(s) {
+                return ::core::option::Option::Some(Self::#variant_ident { #member: __first });
+            }
+        });
+    }
+
+    // construct the impl
+    let vis = &input_enum.vis;
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let enum_impl = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis fn try_parse(s: &str) -> ::core::option::Option<Self> {
+                #(#attempts)*
+                ::core::option::Option::None
+            }
+        }
+    };
+    Ok(enum_impl)
+}
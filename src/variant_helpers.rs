@@ -0,0 +1,162 @@
+use heck::ToSnakeCase;
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Error, ItemEnum, Meta, Token, Type, Variant,
+};
+
+pub fn variant_helpers_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    /// The predicate the impl is conditionally compiled with.
+    cfg: Option<Meta>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let cfg = super::parse_cfg_option(input)?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(input.error("Unrecognized option"));
+        }
+
+        Ok(Args { cfg })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let vis = &input_enum.vis;
+
+    // construct the methods for every variant
+    let mut methods = vec![];
+    for variant in &input_enum.variants {
+        let variant_ident = &variant.ident;
+        let cfg = super::cfg_attrs(variant);
+        // the methods of a deprecated variant can use it without silencing the lint for the others
+        let allow_deprecated = super::allow_deprecated(variant);
+        let name = variant_ident.unraw().to_string().to_snake_case();
+
+        // the braced pattern matches every kind of variant
+        let is_variant = Ident::new(&format!("is_{name}"), Span::call_site());
+        methods.push(quote::quote! {
+            #cfg
+            #allow_deprecated
+            #vis fn #is_variant(&self) -> bool {
+                ::core::matches!(self, Self::#variant_ident { .. })
+            }
+        });
+
+        if let Some((pattern, field, field_types)) = make_accessor_parts(variant)? {
+            let as_variant = Ident::new(&format!("as_{name}"), Span::call_site());
+            let into_variant = Ident::new(&format!("into_{name}"), Span::call_site());
+            // the same match binds references or values depending on the receiver
+            let arms = [
+                (
+                    quote::quote!(#cfg Self::#variant_ident #pattern),
+                    quote::quote!(::core::option::Option::Some(#field)),
+                ),
+                (
+                    quote::quote!(_),
+                    quote::quote!(::core::option::Option::None),
+                ),
+            ];
+            // the wildcard arm would be unreachable for a single variant
+            let arms = if input_enum.variants.len() == 1 {
+                &arms[..1]
+            } else {
+                &arms[..]
+            };
+            let field_match = super::make_match(&quote::quote!(self), arms);
+            let (ref_type, owned_type) = match field_types.as_slice() {
+                [field_type] => (quote::quote!(&#field_type), quote::quote!(#field_type)),
+                _ => (
+                    quote::quote!((#(&#field_types),*)),
+                    quote::quote!((#(#field_types),*)),
+                ),
+            };
+            methods.push(quote::quote! {
+                #cfg
+                #allow_deprecated
+                #vis fn #as_variant(&self) -> ::core::option::Option<#ref_type> {
+                    #field_match
+                }
+                #cfg
+                #allow_deprecated
+                #vis fn #into_variant(self) -> ::core::option::Option<#owned_type> {
+                    #field_match
+                }
+            });
+        }
+    }
+
+    // construct the impl
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote!(#[cfg(#cfg)]));
+    Ok(quote::quote! {
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+/// Makes the pattern that binds the fields of the variant, the expression that refers to them
+/// and the types of the fields for the `as_{variant}` and `into_{variant}` accessors.
+/// A variant with a single field or a delegate field refers to that field,
+/// and other variants to all of their fields in a tuple. Unit variants have no accessors.
+fn make_accessor_parts(
+    variant: &Variant,
+) -> syn::Result<Option<(TokenStream2, TokenStream2, Vec<&Type>)>> {
+    if variant.fields.is_empty() {
+        return Ok(None);
+    }
+    let delegate = match super::marked_field(variant)? {
+        Some(marked) => Some(marked),
+        None => super::named_field(variant)?,
+    };
+    let parts = match delegate {
+        Some((member, field)) => (
+            quote::quote!({ #member: __field, .. }),
+            quote::quote!(__field),
+            vec![&field.ty],
+        ),
+        None => {
+            let members = super::members(&variant.fields);
+            let bindings = (0..members.len())
+                .map(|i| Ident::new(&format!("__field{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let field_types = variant
+                .fields
+                .iter()
+                .map(|field| &field.ty)
+                .collect::<Vec<_>>();
+            let pattern = quote::quote!({ #(#members: #bindings),* });
+            let expr = match bindings.as_slice() {
+                [binding] => quote::quote!(#binding),
+                _ => quote::quote!((#(#bindings),*)),
+            };
+            (pattern, expr, field_types)
+        }
+    };
+    Ok(Some(parts))
+}
@@ -0,0 +1,135 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Error, Fields, Ident, ItemEnum, Meta, Token,
+};
+
+pub fn variant_names_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(arg as Args);
+    let input_enum = syn::parse_macro_input!(input as ItemEnum);
+
+    let enum_impl = match make_impl(&args, &input_enum) {
+        Ok(enum_impl) => enum_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    // return the enum without helper attributes and impl
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
+    TokenStream::from(quote::quote! {
+        #input_enum
+        #enum_impl
+    })
+}
+
+struct Args {
+    /// Generate the `from_name` constructor.
+    from_name: bool,
+    /// The predicate the impl is conditionally compiled with.
+    cfg: Option<Meta>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        // loop over the input and parse options
+        let mut from_name = false;
+        let mut cfg = None;
+        while !input.is_empty() {
+            if let Some(predicate) = super::parse_cfg_option(input)? {
+                cfg = Some(predicate);
+            } else {
+                let flag: Ident = input.parse()?;
+                if flag == "from_name" {
+                    from_name = true;
+                } else {
+                    return Err(Error::new(flag.span(), "Unrecognized flag"));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Args { from_name, cfg })
+    }
+}
+
+fn make_impl(args: &Args, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let vis = &input_enum.vis;
+
+    // construct the names and the arms for variant_name
+    let mut names = vec![];
+    let mut name_arms = vec![];
+    for variant in &input_enum.variants {
+        let variant_ident = &variant.ident;
+        let name = variant_ident.unraw().to_string();
+        let cfg = super::cfg_attrs(variant);
+        let allow_deprecated = super::allow_deprecated(variant);
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote::quote!(Self::#variant_ident { .. }),
+            Fields::Unnamed(_) => quote::quote!(Self::#variant_ident(..)),
+            Fields::Unit => quote::quote!(Self::#variant_ident),
+        };
+        name_arms.push((
+            quote::quote!(#cfg #allow_deprecated #pattern),
+            quote::quote!(#name),
+        ));
+        names.push(quote::quote!(#cfg #name));
+    }
+
+    // construct from_name
+    let from_name = if args.from_name {
+        let field_type = super::common_field_type(input_enum)?;
+        let mut from_name_arms = vec![];
+        for variant in &input_enum.variants {
+            let field = super::only_field(variant)?;
+
+            let variant_ident = &variant.ident;
+            let name = variant_ident.unraw().to_string();
+            let cfg = super::cfg_attrs(variant);
+            let allow_deprecated = super::allow_deprecated(variant);
+            let constructor = if let Some(field_ident) = &field.ident {
+                quote::quote!(Self::#variant_ident { #field_ident: value })
+            } else {
+                quote::quote!(Self::#variant_ident(value))
+            };
+            from_name_arms.push(quote::quote! {
+                #cfg
+                #allow_deprecated
+                #name => ::core::option::Option::Some(#constructor)
+            });
+        }
+        Some(quote::quote! {
+            #vis fn from_name(name: &str, value: #field_type) -> ::core::option::Option<Self> {
+                match name {
+                    #(#from_name_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let name_match = super::make_match(&quote::quote!(self), &name_arms);
+
+    // construct the impl
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote!(#[cfg(#cfg)]));
+    let enum_impl = quote::quote! {
+        #cfg
+        #[automatically_derived]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #vis const VARIANT_NAMES: &'static [&'static str] = &[#(#names),*];
+            #vis const VARIANTS: &'static [&'static str] = Self::VARIANT_NAMES;
+            #vis fn variant_name(&self) -> &'static str {
+                #name_match
+            }
+            #from_name
+        }
+    };
+    Ok(enum_impl)
+}
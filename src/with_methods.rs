@@ -1,107 +1,1533 @@
+use heck::ToUpperCamelCase;
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::ToTokens;
 use syn::{
+    ext::IdentExt,
     parse::{Error, Parse, ParseStream},
     spanned::Spanned,
-    FnArg, ItemEnum, Receiver, Signature, Visibility,
+    token,
+    visit_mut::VisitMut,
+    Attribute, Expr, ExprClosure, FnArg, GenericArgument, GenericParam, Ident, ItemEnum, Lifetime,
+    Member, Pat, PatIdent, Path, PathArguments, Receiver, ReturnType, Signature, Token, Type,
+    TypeReference, Variant, Visibility,
 };
 
 pub fn with_methods_impl(arg: TokenStream, input: TokenStream) -> TokenStream {
-    let input_methods = syn::parse_macro_input!(arg as Methods);
+    let args = match super::parse_args::<Args>(
+        arg.into(),
+        "Expected a list of method signatures, optionally preceded by options",
+    ) {
+        Ok(args) => args,
+        Err(err) => return err.into_compile_error().into(),
+    };
     let input_enum = syn::parse_macro_input!(input as ItemEnum);
 
-    // construct the methods
-    let mut methods = vec![];
-    for (vis, sig) in input_methods.0 {
-        match make_method(vis, sig, &input_enum) {
-            Ok(method) => methods.push(method),
-            Err(err) => return err.into_compile_error().into(),
+    // construct the checks
+    let mut checks = vec![];
+    if args.check {
+        for method in &args.methods {
+            match make_checks(method, args.field.as_ref(), &input_enum) {
+                Ok(method_checks) => checks.extend(method_checks),
+                Err(err) => return err.into_compile_error().into(),
+            }
         }
     }
+    // the checks are made in an anonymous const so that they don't add a name to the enum's namespace,
+    // which would clash with the checks of another invocation on the same enum
+    let (impl_generics, _, where_clause) = input_enum.generics.split_for_impl();
+    let check_fn = if checks.is_empty() {
+        None
+    } else {
+        Some(quote::quote! {
+            const _: () = {
+                #[allow(dead_code)]
+                fn __impl_enum_check #impl_generics () #where_clause {
+                    #(#checks)*
+                }
+            };
+        })
+    };
+
+    // construct the methods and any items they require,
+    // grouping the methods by their trait for trait impls
+    if args.ref_mut_impls && !args.trait_impls {
+        return Error::new(
+            Span::call_site(),
+            "The `ref_mut_impls` option requires the `trait_impls` option",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    // the fields of methods taking `self: Pin<&mut Self>` are pinned structurally
+    let pinned = args.methods.iter().any(|method| {
+        method.sig.receiver().map_or(false, |receiver| {
+            receiver.colon_token.is_some() && is_pin_mut_self(&receiver.ty)
+        })
+    });
+    let pin_guards = if pinned {
+        match make_pin_guards(args.field.as_ref(), &input_enum) {
+            Ok(pin_guards) => Some(pin_guards),
+            Err(err) => return err.into_compile_error().into(),
+        }
+    } else {
+        None
+    };
+
+    // the allocating types are taken from the alloc crate in no_std crates
+    let alloc = if args.alloc {
+        quote::quote!(::alloc)
+    } else {
+        quote::quote!(::std)
+    };
 
-    // construct the impl
     let enum_ident = &input_enum.ident;
     let (impl_generics, ty_generics, where_clause) = &input_enum.generics.split_for_impl();
+    let mut methods = vec![];
+    let mut trait_methods: Vec<(Path, Vec<TokenStream2>, Vec<TokenStream2>)> = vec![];
+    let mut items = vec![];
+    for mut method in args.methods {
+        let trait_path = match &method.trait_path {
+            Some(trait_path) if args.trait_impls => {
+                if method.sig.receiver().is_none() {
+                    return Error::new(
+                        method.sig.span(),
+                        "Methods without a receiver are not supported in trait impls",
+                    )
+                    .into_compile_error()
+                    .into();
+                }
+                method.vis = Visibility::Inherited;
+                Some(trait_path.clone())
+            }
+            _ => None,
+        };
+        let forward = match &trait_path {
+            Some(trait_path) if args.ref_mut_impls => {
+                let enum_type = quote::quote!(#enum_ident #ty_generics);
+                match make_forwarding_method(
+                    &method.attrs,
+                    method.sig.clone(),
+                    &enum_type,
+                    trait_path,
+                ) {
+                    Ok(forward) => Some(forward),
+                    Err(err) => return err.into_compile_error().into(),
+                }
+            }
+            _ => None,
+        };
+        let (method, item) = match make_method(method, args.field.as_ref(), &alloc, &input_enum) {
+            Ok(method) => method,
+            Err(err) => return err.into_compile_error().into(),
+        };
+        items.extend(item);
+        match trait_path {
+            Some(trait_path) => {
+                let key = trait_path.to_token_stream().to_string();
+                match trait_methods
+                    .iter_mut()
+                    .find(|(path, _, _)| path.to_token_stream().to_string() == key)
+                {
+                    Some((_, group, forwards)) => {
+                        group.push(method);
+                        forwards.extend(forward);
+                    }
+                    None => trait_methods.push((
+                        trait_path,
+                        vec![method],
+                        forward.into_iter().collect(),
+                    )),
+                }
+            }
+            None => methods.push(method),
+        }
+    }
+
+    // construct the impl, marked as generated
     let enum_impl = quote::quote! {
+        #[automatically_derived]
         impl #impl_generics #enum_ident #ty_generics #where_clause {
             #(#methods)*
         }
     };
+    let trait_impls = trait_methods
+        .iter()
+        .map(|(trait_path, methods, _)| {
+            quote::quote! {
+                #[automatically_derived]
+                impl #impl_generics #trait_path for #enum_ident #ty_generics #where_clause {
+                    #(#methods)*
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    // the mutable references to the enum forward to the impls of the enum
+    let mut ref_mut_generics = input_enum.generics.clone();
+    ref_mut_generics
+        .params
+        .insert(0, syn::parse_quote!('__impl_enum));
+    let (ref_mut_impl_generics, _, _) = ref_mut_generics.split_for_impl();
+    let ref_mut_impls = trait_methods
+        .iter()
+        .filter(|_| args.ref_mut_impls)
+        .map(|(trait_path, _, forwards)| {
+            quote::quote! {
+                #[automatically_derived]
+                impl #ref_mut_impl_generics #trait_path for &'__impl_enum mut #enum_ident #ty_generics #where_clause {
+                    #(#forwards)*
+                }
+            }
+        })
+        .collect::<Vec<_>>();
 
-    // return the enum and impl
+    // return the enum without helper attributes, impl and items
+    let mut input_enum = input_enum;
+    super::strip_helper_attrs(&mut input_enum);
     TokenStream::from(quote::quote! {
         #input_enum
         #enum_impl
+        #(#trait_impls)*
+        #(#ref_mut_impls)*
+        #(#items)*
+        #check_fn
+        #pin_guards
     })
 }
 
-struct Methods(Vec<(Visibility, Signature)>);
+struct Args {
+    /// Check that the field types implement the methods in their traits before delegating.
+    check: bool,
+    /// Implement the methods with `in Trait` in trait impls instead of the inherent impl.
+    trait_impls: bool,
+    /// Also implement the traits for mutable references to the enum.
+    ref_mut_impls: bool,
+    /// The member of the field to delegate to in every variant.
+    field: Option<Member>,
+    /// Use the `alloc` crate instead of `std` for the allocating types.
+    alloc: bool,
+    methods: Vec<Method>,
+}
 
-impl Parse for Methods {
+impl Parse for Args {
     fn parse(input: ParseStream) -> Result<Self, Error> {
+        let mut check = false;
+        let mut trait_impls = false;
+        let mut ref_mut_impls = false;
+        let mut field = None;
+        let mut alloc = false;
+        // the options are followed by the signatures in braces
+        let has_options = input.peek(Ident) || input.peek(token::Brace);
+        let content;
+        let methods_input = if has_options {
+            while !input.peek(token::Brace) {
+                let option: Ident = input.parse()?;
+                if option == "check" {
+                    check = true;
+                } else if option == "trait_impls" {
+                    trait_impls = true;
+                } else if option == "ref_mut_impls" {
+                    ref_mut_impls = true;
+                } else if option == "field" {
+                    input.parse::<Token![=]>()?;
+                    field = Some(input.parse()?);
+                } else if option == "alloc" {
+                    alloc = true;
+                } else {
+                    return Err(Error::new(option.span(), "Unrecognized option"));
+                }
+                input.parse::<Token![,]>()?;
+            }
+            syn::braced!(content in input);
+            input.parse::<Option<Token![,]>>()?;
+            &content
+        } else {
+            input
+        };
+
         // loop over the input and parse functions
-        let mut methods = vec![];
-        while !input.is_empty() {
-            let vis: Visibility = input.parse()?;
-            let sig: Signature = input.parse()?;
-            methods.push((vis, sig));
+        let mut methods: Vec<Method> = vec![];
+        while !methods_input.is_empty() {
+            methods.push(methods_input.parse()?);
+            // the signatures may be separated by semicolons or commas
+            if methods_input.peek(Token![;]) {
+                methods_input.parse::<Token![;]>()?;
+            } else if methods_input.peek(Token![,]) {
+                methods_input.parse::<Token![,]>()?;
+            }
         }
 
-        Ok(Methods(methods))
+        // methods in the same impl must have different names, the trait impls are separate
+        let mut names = vec![];
+        for method in &methods {
+            let trait_path = method
+                .trait_path
+                .as_ref()
+                .filter(|_| trait_impls)
+                .map(|trait_path| trait_path.to_token_stream().to_string());
+            let name = (trait_path, method.sig.ident.unraw().to_string());
+            if names.contains(&name) {
+                return Err(Error::new(
+                    method.sig.span(),
+                    format!("The method `{}` is listed more than once", name.1),
+                ));
+            }
+            names.push(name);
+        }
+
+        // the methods of trait impls must keep the signatures of the traits
+        for method in &methods {
+            if !trait_impls || method.trait_path.is_none() {
+                continue;
+            }
+            if method.tagged {
+                return Err(Error::new(
+                    method.sig.span(),
+                    "The `tagged` option is not supported with `trait_impls`",
+                ));
+            }
+            if method.tait {
+                return Err(Error::new(
+                    method.sig.span(),
+                    "The `tait` option is not supported with `trait_impls`",
+                ));
+            }
+            if let Some(delegate) = &method.delegate {
+                return Err(Error::new(
+                    delegate.span(),
+                    "Methods in trait impls must have the name of the trait's method",
+                ));
+            }
+        }
+
+        Ok(Args {
+            check,
+            trait_impls,
+            ref_mut_impls,
+            field,
+            alloc,
+            methods,
+        })
     }
 }
 
-fn make_method(
+struct Method {
+    /// The attributes forwarded to the generated method, such as `#[inline]`.
+    attrs: Vec<Attribute>,
     vis: Visibility,
-    mut sig: Signature,
+    sig: Signature,
+    /// The name of the method called on the fields, if it differs from the name of the generated method.
+    delegate: Option<Ident>,
+    /// The trait to call the method from.
+    trait_path: Option<Path>,
+    /// Return a named type alias impl trait instead of using an `async fn`.
+    tait: bool,
+    /// Box the error of the delegated call's result into the error type of the signature.
+    box_error: bool,
+    /// The macro invoked with the name of the method before delegating.
+    hook: Option<Path>,
+    /// Return the index of the variant along with the result.
+    tagged: bool,
+    /// The method of the enum whose result the method is called on instead of matching on the variants.
+    through: Option<Ident>,
+    /// Move the result of the delegated call into the smart pointer trait object of the signature.
+    wrap: bool,
+    /// Call the method by reference and return the field along with the result.
+    parts: bool,
+}
+
+impl Parse for Method {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let mut tait = false;
+        let mut box_error = false;
+        let mut hook = None;
+        let mut tagged = false;
+        let mut through = None;
+        let mut wrap = false;
+        let mut parts = false;
+        let mut attrs = vec![];
+        for attr in input.call(Attribute::parse_outer)? {
+            if !super::is_helper_attr(&attr) {
+                attrs.push(attr);
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tait") {
+                    tait = true;
+                    Ok(())
+                } else if meta.path.is_ident("box_error") {
+                    box_error = true;
+                    Ok(())
+                } else if meta.path.is_ident("hook") {
+                    hook = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("tagged") {
+                    tagged = true;
+                    Ok(())
+                } else if meta.path.is_ident("through") {
+                    through = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("wrap") {
+                    wrap = true;
+                    Ok(())
+                } else if meta.path.is_ident("parts") {
+                    parts = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Unrecognized option"))
+                }
+            })?;
+        }
+        let vis: Visibility = input.parse()?;
+        let sig: Signature = input.parse()?;
+        let delegate = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        // `via` is accepted as an alternative to `in`
+        let trait_path = if input.peek(Token![in]) {
+            input.parse::<Token![in]>()?;
+            Some(input.parse()?)
+        } else if input.peek(Ident) && input.fork().parse::<Ident>()? == "via" {
+            input.parse::<Ident>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        // a body after the signature is a common mistake that would otherwise confuse the parsing of the next method
+        if input.peek(token::Brace) {
+            return Err(input.error("Method bodies are generated, provide only the signature"));
+        }
+
+        Ok(Method {
+            attrs,
+            vis,
+            sig,
+            delegate,
+            trait_path,
+            tait,
+            box_error,
+            hook,
+            tagged,
+            through,
+            wrap,
+            parts,
+        })
+    }
+}
+
+fn make_method(
+    method: Method,
+    field: Option<&Member>,
+    alloc: &TokenStream2,
     input_enum: &ItemEnum,
-) -> syn::Result<TokenStream2> {
-    // rename receivers to __first for the call
+) -> syn::Result<(TokenStream2, Option<TokenStream2>)> {
+    let Method {
+        attrs,
+        vis,
+        mut sig,
+        delegate,
+        trait_path,
+        tait,
+        box_error,
+        hook,
+        tagged,
+        through,
+        wrap,
+        parts,
+    } = method;
+
+    // methods taking `self: Box<Self>` match on the boxed value and box the field for the call
+    let boxed_receiver = sig.receiver().map_or(false, |receiver| {
+        receiver.colon_token.is_some() && is_box_self(&receiver.ty)
+    });
+    // methods taking `self: Pin<&mut Self>` project the pin to the field
+    let pinned_receiver = sig.receiver().map_or(false, |receiver| {
+        receiver.colon_token.is_some() && is_pin_mut_self(&receiver.ty)
+    });
+    // rename receivers to __first for the call, borrowing the field if it's returned with the result
+    let borrow = if parts { Some(quote::quote!(&)) } else { None };
+    let mut arg_idents = bind_args(&mut sig).into_iter();
     let method_call_args = sig
         .inputs
         .iter()
         .map(|fa| match fa {
-            FnArg::Typed(t) => t.pat.to_token_stream(),
+            FnArg::Typed(_) => arg_idents.next().expect("missing argument").to_token_stream(),
+            FnArg::Receiver(Receiver { self_token, .. }) if boxed_receiver => {
+                quote::quote_spanned! { self_token.span() => #alloc::boxed::Box::new(__first) }
+            }
+            // SAFETY: the field is pinned structurally, the pin guards prevent moving it out of the pinned enum
+            FnArg::Receiver(Receiver { self_token, .. }) if pinned_receiver => {
+                quote::quote_spanned! { self_token.span() => unsafe { ::core::pin::Pin::new_unchecked(__first) } }
+            }
             FnArg::Receiver(Receiver { self_token, .. }) => {
-                quote::quote_spanned! { self_token.span() =>  __first }
+                quote::quote_spanned! { self_token.span() => #borrow __first }
             }
         })
         .collect::<Vec<_>>();
+    // the arguments following the receiver for method call syntax
+    let has_receiver = sig.receiver().is_some();
+    let method_call_rest_args = method_call_args
+        .iter()
+        .skip(usize::from(has_receiver))
+        .collect::<Vec<_>>();
     // add &self receiver if none for the signature
     if sig.receiver().is_none() {
         sig.inputs.insert(0, syn::parse_quote!(&self));
     }
+    // await the delegated call for async methods
+    let await_call = sig.asyncness.map(|_| quote::quote!(.await));
+    // pass the generic arguments explicitly when they can't be inferred from the arguments and return type
+    let turbofish = turbofish(&sig);
+    // methods returning Self wrap the result back into the variant
+    let rewrap = returns_self(&sig.output, input_enum);
+    // methods returning Vec<Self> or Option<Self> wrap each item back into the variant
+    let container = self_container(&sig.output, input_enum);
+    // move the results of the delegated calls into the smart pointer of the return type
+    let wrap_result = if wrap {
+        let (constructor, output) = smart_pointer(&sig.output, alloc).ok_or_else(|| {
+            Error::new(
+                sig.output.span(),
+                "The `wrap` option requires a return type of the form `Box<dyn Trait>`, `Rc<dyn Trait>` or `Arc<dyn Trait>`",
+            )
+        })?;
+        Some((constructor, output.clone()))
+    } else {
+        None
+    };
+    // convert the errors of the delegated calls into the boxed error type,
+    // which boxes concrete errors and keeps errors that are already boxed
+    let map_error = if box_error {
+        if result_error_type(&sig.output).is_none() {
+            return Err(Error::new(
+                sig.output.span(),
+                "The `box_error` option requires a return type of the form `Result<T, E>`",
+            ));
+        }
+        Some(quote::quote! {
+            .map_err(::core::convert::Into::into)
+        })
+    } else {
+        None
+    };
+    // methods returning the index of the variant return a tuple of the index and the result
+    if tagged {
+        let output = match &sig.output {
+            ReturnType::Default => quote::quote!(()),
+            ReturnType::Type(_, ty) => ty.to_token_stream(),
+        };
+        sig.output = syn::parse_quote!(-> (usize, #output));
+    }
+    let by_value = matches!(
+        sig.receiver(),
+        Some(Receiver {
+            reference: None,
+            colon_token: None,
+            ..
+        })
+    );
+    if parts && !(by_value && !rewrap && container.is_none()) {
+        return Err(Error::new(
+            sig.span(),
+            "The `parts` option requires the method to take `self` and return `(Field, R)`",
+        ));
+    }
+
+    if sig.constness.is_some() && trait_path.is_some() {
+        return Err(Error::new(
+            sig.constness.span(),
+            "`const fn` methods can only be delegated to inherent methods",
+        ));
+    }
+
+    // invoke the hook with the name of the method before the match
+    let hook = hook.map(|hook| {
+        let method_name = sig.ident.unraw().to_string();
+        quote::quote!(#hook!(#method_name);)
+    });
+    // the name is taken from the signature, which may not follow the naming conventions
+    let allow_non_snake_case = if sig
+        .ident
+        .unraw()
+        .to_string()
+        .chars()
+        .any(char::is_uppercase)
+    {
+        Some(quote::quote!(#[allow(non_snake_case)]))
+    } else {
+        None
+    };
+    // the arguments are unused when there are no variants to delegate to
+    let allow_unused_variables = if input_enum.variants.is_empty() {
+        Some(quote::quote!(#[allow(unused_variables)]))
+    } else {
+        None
+    };
+    // calling the method on a skipped variant panics
+    let panics_doc = super::skipped_panics_doc(input_enum)?;
+    let method_attrs =
+        quote::quote!(#(#attrs)* #panics_doc #allow_non_snake_case #allow_unused_variables);
+
+    // methods called through another method don't match on the variants
+    if let Some(through) = through {
+        if rewrap || container.is_some() || tagged || tait {
+            return Err(Error::new(
+                sig.span(),
+                "The `through` option is not supported with `tait`, `tagged` or methods returning `Self`",
+            ));
+        }
+        let method_ident = delegate.as_ref().unwrap_or(&sig.ident);
+        let method = quote::quote! {
+            #method_attrs
+            #vis #sig {
+                #hook
+                self.#through().#method_ident #turbofish (#(#method_call_rest_args),*) #await_call #map_error
+            }
+        };
+        return Ok((method, None));
+    }
 
     // make match arm for every variant
     let mut match_arms = vec![];
-    for variant in &input_enum.variants {
-        let first_field = super::first_field(variant)?;
-
+    for (variant_index, variant) in input_enum.variants.iter().enumerate() {
+        let options = VariantOptions::parse(variant)?;
+        if super::is_skipped(variant)? {
+            match_arms.push(super::skipped_arm(variant, &sig.ident));
+            continue;
+        }
         let variant_ident = &variant.ident;
+        let cfg = super::cfg_attrs(variant);
+        let allow_deprecated = super::allow_deprecated(variant);
+        // pairs the result with the index of the variant
+        let tag = |expr: TokenStream2| {
+            if tagged {
+                quote::quote!((#variant_index, #expr))
+            } else {
+                expr
+            }
+        };
+        if options.passthrough {
+            if !(rewrap && by_value) {
+                return Err(Error::new(
+                    sig.span(),
+                    format!("Methods must take `self` and return `Self` to pass through the variant `{variant_ident}`"),
+                ));
+            }
+            let expr = tag(quote::quote!(__passthrough));
+            match_arms.push((
+                quote::quote!(#cfg #allow_deprecated __passthrough @ Self::#variant_ident { .. }),
+                expr,
+            ));
+            continue;
+        }
+        if let Some(default) = &options.default {
+            match_arms.push((
+                quote::quote!(#cfg #allow_deprecated Self::#variant_ident { .. }),
+                tag(default.to_token_stream()),
+            ));
+            continue;
+        }
+
+        let (first_member, first_field) =
+            super::delegate_field(variant, options.field.as_ref().or(field))?;
+
         let first_field_type = &first_field.ty;
-        let method_ident = &sig.ident;
-        let match_arm = if let Some(first_field_ident) = &first_field.ident {
-            quote::quote! {
-                Self::#variant_ident { #first_field_ident: __first, .. }
-                    => <#first_field_type> :: #method_ident (#(#method_call_args),* )
+        let method_ident = delegate.as_ref().unwrap_or(&sig.ident);
+        if parts && options.access.is_some() {
+            return Err(Error::new(
+                sig.span(),
+                format!("The `parts` option is not supported for the variant `{variant_ident}` with `access`"),
+            ));
+        }
+        if (boxed_receiver || pinned_receiver) && options.access.is_some() {
+            return Err(Error::new(
+                sig.span(),
+                format!("Methods taking `self: Box<Self>` or `self: Pin<&mut Self>` are not supported for the variant `{variant_ident}` with `access`"),
+            ));
+        }
+        let call = match options.access {
+            // the type of the accessed value is unknown so the method is called with method call syntax
+            Some(access) if has_receiver => {
+                let access_pat = &access.inputs[0];
+                let access_body = &access.body;
+                quote::quote! {{
+                    #[allow(unused_mut)]
+                    let mut __first = {
+                        let #access_pat = __first;
+                        #access_body
+                    };
+                    __first.#method_ident #turbofish (#(#method_call_rest_args),*) #await_call
+                }}
             }
+            // method call syntax finds methods through auto-deref, such as those of a `Box<dyn Trait>` field
+            _ if has_receiver && trait_path.is_none() => {
+                let receiver = &method_call_args[0];
+                quote::quote! {
+                    (#receiver).#method_ident #turbofish (#(#method_call_rest_args),*) #await_call
+                }
+            }
+            _ => {
+                let qualified_type = match &trait_path {
+                    Some(trait_path) => quote::quote!(<#first_field_type as #trait_path>),
+                    None => quote::quote!(<#first_field_type>),
+                };
+                quote::quote! {
+                    #qualified_type :: #method_ident #turbofish (#(#method_call_args),* ) #await_call
+                }
+            }
+        };
+        let call = quote::quote!(#call #map_error);
+        let call = match &wrap_result {
+            Some((constructor, output)) => quote::quote!(#constructor(#call) as #output),
+            None => call,
+        };
+        // the field is moved out after the call that borrows it
+        let call = if parts {
+            quote::quote! {{
+                let __result = #call;
+                (__first, __result)
+            }}
         } else {
-            quote::quote! {
-                Self::#variant_ident ( __first, .. )
-                    => <#first_field_type> :: #method_ident (#(#method_call_args),* )
+            call
+        };
+        let match_arm = if let Some(container) = &container {
+            // the other fields can only be moved into a single new value
+            let other_members = super::members(&variant.fields)
+                .into_iter()
+                .filter(|member| member != &first_member)
+                .collect::<Vec<_>>();
+            if !other_members.is_empty() && matches!(container, SelfContainer::Vec) {
+                return Err(Error::new(
+                    sig.span(),
+                    format!("Methods returning `{container}` are only supported for variants with one field, unlike `{variant_ident}`"),
+                ));
+            }
+            if !other_members.is_empty() && !by_value {
+                return Err(Error::new(
+                    sig.span(),
+                    format!("Methods must take `self` to return `{container}` for the variant `{variant_ident}` with multiple fields"),
+                ));
             }
+            let others = (1..=other_members.len())
+                .map(|i| Ident::new(&format!("__field{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let expr = match container {
+                SelfContainer::Vec => quote::quote! {
+                    ::core::iter::IntoIterator::into_iter(#call)
+                        .map(|__item| Self::#variant_ident { #first_member: __item })
+                        .collect::<#alloc::vec::Vec<_>>()
+                },
+                SelfContainer::Option => quote::quote! {
+                    ::core::option::Option::map(#call, move |__item| Self::#variant_ident { #first_member: __item, #(#other_members: #others),* })
+                },
+                SelfContainer::Result => quote::quote! {
+                    ::core::result::Result::map(#call, move |__item| Self::#variant_ident { #first_member: __item, #(#other_members: #others),* })
+                },
+            };
+            (
+                quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #first_member: __first, #(#other_members: #others),* }),
+                tag(expr),
+            )
+        } else if rewrap {
+            // bind every field to move the other fields into the new value
+            let other_members = super::members(&variant.fields)
+                .into_iter()
+                .filter(|member| member != &first_member)
+                .collect::<Vec<_>>();
+            if !other_members.is_empty() && !by_value {
+                return Err(Error::new(
+                    sig.span(),
+                    format!("Methods must take `self` to return `Self` for the variant `{variant_ident}` with multiple fields"),
+                ));
+            }
+            let others = (1..=other_members.len())
+                .map(|i| Ident::new(&format!("__field{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let expr = tag(quote::quote! {
+                Self::#variant_ident { #first_member: #call, #(#other_members: #others),* }
+            });
+            (
+                quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #first_member: __first, #(#other_members: #others),* }),
+                expr,
+            )
+        } else {
+            (
+                quote::quote!(#cfg #allow_deprecated Self::#variant_ident { #first_member: __first, .. }),
+                tag(call),
+            )
         };
         match_arms.push(match_arm);
     }
 
+    let matched_value = if boxed_receiver {
+        quote::quote!(*self)
+    } else if pinned_receiver {
+        // SAFETY: the fields are only accessed as pinned projections, which the pin guards keep sound
+        quote::quote!(unsafe { ::core::pin::Pin::get_unchecked_mut(self) })
+    } else {
+        quote::quote!(self)
+    };
+    let matched = super::make_match(&matched_value, &match_arms);
+    let body = quote::quote! {
+        #hook
+        #matched
+    };
+
+    if tait {
+        return make_tait_method(vis, sig, body, method_attrs, input_enum);
+    }
+
     // generate new block for the function
     let method = quote::quote! {
+        #method_attrs
         #vis #sig {
-            match self {
-                #(#match_arms),*
+            #body
+        }
+    };
+    Ok((method, None))
+}
+
+/// Makes the items that keep the structural pinning of the fields sound:
+/// an impl of `Unpin` for the enum that requires the pinned fields to be `Unpin`,
+/// which conflicts with an impl of `Unpin` written for the enum,
+/// and an impl of a trait that conflicts with an impl of `Drop` for the enum,
+/// which could move the pinned fields.
+fn make_pin_guards(field: Option<&Member>, input_enum: &ItemEnum) -> syn::Result<TokenStream2> {
+    let mut field_types = vec![];
+    for variant in &input_enum.variants {
+        let options = VariantOptions::parse(variant)?;
+        if options.passthrough || options.default.is_some() || super::is_skipped(variant)? {
+            continue;
+        }
+        let (_, first_field) = super::delegate_field(variant, options.field.as_ref().or(field))?;
+        field_types.push(&first_field.ty);
+    }
+
+    let enum_ident = &input_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = input_enum.generics.split_for_impl();
+    // the bounds of the impl mention a lifetime so that they're not trivially false for fields that aren't `Unpin`
+    let mut origin_generics = input_enum.generics.clone();
+    origin_generics
+        .params
+        .insert(0, syn::parse_quote!('__impl_enum_pin));
+    let (origin_impl_generics, origin_ty_generics, _) = origin_generics.split_for_impl();
+    let origin_where = match where_clause {
+        Some(where_clause) => {
+            let predicates = &where_clause.predicates;
+            quote::quote!(where __ImplEnumOrigin #origin_ty_generics: ::core::marker::Unpin, #predicates)
+        }
+        None => quote::quote!(where __ImplEnumOrigin #origin_ty_generics: ::core::marker::Unpin),
+    };
+    Ok(quote::quote! {
+        const _: () = {
+            #[allow(dead_code, non_camel_case_types)]
+            struct __ImplEnumOrigin #origin_impl_generics #where_clause {
+                __lifetime: ::core::marker::PhantomData<&'__impl_enum_pin ()>,
+                __enum: ::core::marker::PhantomData<fn() -> #enum_ident #ty_generics>,
+                __fields: (#(#field_types,)*),
             }
+            impl #origin_impl_generics ::core::marker::Unpin for #enum_ident #ty_generics #origin_where {}
+
+            trait __ImplEnumMustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> __ImplEnumMustNotImplDrop for T {}
+            impl #impl_generics __ImplEnumMustNotImplDrop for #enum_ident #ty_generics #where_clause {}
+        };
+    })
+}
+
+/// Makes a method for the impl of a mutable reference to the enum
+/// that forwards the call to the impl of the enum.
+fn make_forwarding_method(
+    attrs: &[Attribute],
+    mut sig: Signature,
+    enum_type: &TokenStream2,
+    trait_path: &Path,
+) -> syn::Result<TokenStream2> {
+    match sig.receiver() {
+        Some(Receiver {
+            reference: Some(_),
+            mutability: Some(_),
+            colon_token: None,
+            ..
+        }) => {}
+        _ => {
+            return Err(Error::new(
+                sig.span(),
+                "The `ref_mut_impls` option only supports methods that take `&mut self`",
+            ))
+        }
+    }
+    let args = bind_args(&mut sig);
+    let method_ident = &sig.ident;
+    let await_call = sig.asyncness.map(|_| quote::quote!(.await));
+    let turbofish = turbofish(&sig);
+    Ok(quote::quote! {
+        #(#attrs)*
+        #sig {
+            <#enum_type as #trait_path>::#method_ident #turbofish (&mut **self, #(#args),*) #await_call
         }
+    })
+}
+
+/// Makes statements that check that the field type of every variant has the method in its trait,
+/// spanned at the field types so that errors point at the offending variant.
+fn make_checks(
+    method: &Method,
+    field: Option<&Member>,
+    input_enum: &ItemEnum,
+) -> syn::Result<Vec<TokenStream2>> {
+    let trait_path = match &method.trait_path {
+        Some(trait_path) => trait_path,
+        None => return Ok(vec![]),
     };
-    Ok(method)
+    // the generic parameters of a generic method can't be inferred without a call
+    let generic = method
+        .sig
+        .generics
+        .params
+        .iter()
+        .any(|param| !matches!(param, GenericParam::Lifetime(_)));
+    if generic {
+        return Ok(vec![]);
+    }
+
+    let method_ident = method.delegate.as_ref().unwrap_or(&method.sig.ident);
+    let mut checks = vec![];
+    for variant in &input_enum.variants {
+        let options = VariantOptions::parse(variant)?;
+        if options.passthrough
+            || options.access.is_some()
+            || options.default.is_some()
+            || super::is_skipped(variant)?
+        {
+            continue;
+        }
+        let (_, first_field) = super::delegate_field(variant, options.field.as_ref().or(field))?;
+        let first_field_type = &first_field.ty;
+        let cfg = super::cfg_attrs(variant);
+        checks.push(quote::quote_spanned! { first_field_type.span() =>
+            #cfg
+            let _ = <#first_field_type as #trait_path>::#method_ident;
+        });
+    }
+    Ok(checks)
+}
+
+/// Returns the names of the arguments of the method for passing them to the delegated call.
+/// Arguments with patterns other than identifiers, such as `(a, b): (u8, u8)`, are not valid expressions,
+/// so they are renamed to `__arg{index}` in the signature.
+fn bind_args(sig: &mut Signature) -> Vec<Ident> {
+    sig.inputs
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(i, fa)| match fa {
+            FnArg::Typed(t) => match &*t.pat {
+                Pat::Ident(PatIdent {
+                    by_ref: None,
+                    subpat: None,
+                    ident,
+                    ..
+                }) => Some(ident.clone()),
+                _ => {
+                    let ident = Ident::new(&format!("__arg{}", i), Span::call_site());
+                    *t.pat = syn::parse_quote!(#ident);
+                    Some(ident)
+                }
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect()
+}
+
+/// Makes the generic arguments for the delegated call
+/// if a type or const parameter of the method is not used in its arguments or return type.
+fn turbofish(sig: &Signature) -> Option<TokenStream2> {
+    fn contains(tokens: TokenStream2, ident: &Ident) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(i) => &i == ident,
+            proc_macro2::TokenTree::Group(g) => contains(g.stream(), ident),
+            _ => false,
+        })
+    }
+
+    let mut used = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(pat_type) => Some(pat_type.ty.to_token_stream()),
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<TokenStream2>();
+    used.extend(sig.output.to_token_stream());
+    let params = sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(&param.ident),
+            GenericParam::Const(param) => Some(&param.ident),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if params.iter().all(|param| contains(used.clone(), param)) {
+        return None;
+    }
+    Some(quote::quote!(::<#(#params),*>))
+}
+
+/// Returns the type argument of a type of the form `Wrapper<T>`, such as `Box<Self>`.
+fn wrapped_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(arg) => Some(arg),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether the type is `Self`.
+fn is_self(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("Self"))
+}
+
+/// Checks whether the type is `Self` or the enum written out with its generic parameters, such as `Enum<T>`.
+fn is_enum_type(ty: &Type, input_enum: &ItemEnum) -> bool {
+    if is_self(ty) {
+        return true;
+    }
+    let segment = match ty {
+        Type::Path(type_path)
+            if type_path.qself.is_none() && type_path.path.segments.len() == 1 =>
+        {
+            &type_path.path.segments[0]
+        }
+        _ => return false,
+    };
+    let (_, ty_generics, _) = input_enum.generics.split_for_impl();
+    segment.ident == input_enum.ident
+        && segment.arguments.to_token_stream().to_string()
+            == ty_generics.to_token_stream().to_string()
+}
+
+/// Checks whether the type is `Box<Self>`.
+fn is_box_self(ty: &Type) -> bool {
+    wrapped_type(ty, "Box").map_or(false, is_self)
+}
+
+/// Checks whether the type is `Pin<&mut Self>`.
+fn is_pin_mut_self(ty: &Type) -> bool {
+    match wrapped_type(ty, "Pin") {
+        Some(Type::Reference(reference)) => {
+            reference.mutability.is_some() && is_self(&reference.elem)
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether the return type is `Self`.
+fn returns_self(output: &ReturnType, input_enum: &ItemEnum) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => is_enum_type(ty, input_enum),
+        ReturnType::Default => false,
+    }
+}
+
+/// Returns the constructor and the type of a return type of the form `Box<dyn Trait>`, `Rc<dyn Trait>` or `Arc<dyn Trait>`.
+fn smart_pointer<'a>(
+    output: &'a ReturnType,
+    alloc: &TokenStream2,
+) -> Option<(TokenStream2, &'a Type)> {
+    let ty = match output {
+        ReturnType::Type(_, ty) => ty,
+        ReturnType::Default => return None,
+    };
+    let segment = match &**ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    let constructor = if segment.ident == "Box" {
+        quote::quote!(#alloc::boxed::Box::new)
+    } else if segment.ident == "Rc" {
+        quote::quote!(#alloc::rc::Rc::new)
+    } else if segment.ident == "Arc" {
+        quote::quote!(#alloc::sync::Arc::new)
+    } else {
+        return None;
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(Type::TraitObject(_)) => Some((constructor, &**ty)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// A container of `Self` returned by a method.
+enum SelfContainer {
+    Vec,
+    Option,
+    Result,
+}
+
+impl std::fmt::Display for SelfContainer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Vec => write!(f, "Vec<Self>"),
+            Self::Option => write!(f, "Option<Self>"),
+            Self::Result => write!(f, "Result<Self, E>"),
+        }
+    }
+}
+
+/// Checks whether the return type is `Vec<Self>`, `Option<Self>` or `Result<Self, E>`.
+fn self_container(output: &ReturnType, input_enum: &ItemEnum) -> Option<SelfContainer> {
+    let ty = match output {
+        ReturnType::Type(_, ty) => ty,
+        ReturnType::Default => return None,
+    };
+    let segment = match &**ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    let container = if segment.ident == "Vec" {
+        SelfContainer::Vec
+    } else if segment.ident == "Option" {
+        SelfContainer::Option
+    } else if segment.ident == "Result" {
+        SelfContainer::Result
+    } else {
+        return None;
+    };
+    let arg_count = match container {
+        SelfContainer::Result => 2,
+        _ => 1,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == arg_count => {
+            match &args.args[0] {
+                GenericArgument::Type(ty) if is_enum_type(ty, input_enum) => Some(container),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the error type `E` of a return type of the form `Result<T, E>`.
+fn result_error_type(output: &ReturnType) -> Option<&Type> {
+    let ty = match output {
+        ReturnType::Type(_, ty) => ty,
+        ReturnType::Default => return None,
+    };
+    let segment = match &**ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Result" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 2 => match &args.args[1] {
+            GenericArgument::Type(error_type) => Some(error_type),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The options of a variant passed with `#[impl_enum(...)]` attributes.
+struct VariantOptions {
+    /// The expression used to access the value the method is called on.
+    access: Option<ExprClosure>,
+    /// Return the variant unchanged instead of delegating.
+    passthrough: bool,
+    /// The member of the field to delegate to.
+    field: Option<Member>,
+    /// The expression evaluated instead of delegating.
+    default: Option<Expr>,
+}
+
+impl VariantOptions {
+    fn parse(variant: &Variant) -> syn::Result<Self> {
+        let mut access = None;
+        let mut passthrough = false;
+        let mut field = None;
+        let mut default = None;
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| super::is_helper_attr(attr))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("access") {
+                    let closure: ExprClosure = meta.value()?.parse()?;
+                    if closure.inputs.len() != 1 {
+                        return Err(Error::new(
+                            closure.inputs.span(),
+                            "The access expression must take exactly one argument",
+                        ));
+                    }
+                    access = Some(closure);
+                    Ok(())
+                } else if meta.path.is_ident("passthrough") {
+                    passthrough = true;
+                    Ok(())
+                } else if meta.path.is_ident("field") {
+                    field = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("to") {
+                    // handled when choosing the delegate field
+                    meta.value()?.parse::<Member>()?;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    // handled when making the arms
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    // without an expression, the default value of the return type is used
+                    default = Some(if meta.input.peek(Token![=]) {
+                        meta.value()?.parse()?
+                    } else {
+                        syn::parse_quote!(::core::default::Default::default())
+                    });
+                    Ok(())
+                } else {
+                    Err(meta.error("Unrecognized option"))
+                }
+            })?;
+        }
+        Ok(VariantOptions {
+            access,
+            passthrough,
+            field,
+            default,
+        })
+    }
+}
+
+/// Generates an async method that returns a named type alias impl trait instead of an anonymous future,
+/// along with the type alias.
+fn make_tait_method(
+    vis: Visibility,
+    mut sig: Signature,
+    body: TokenStream2,
+    method_attrs: TokenStream2,
+    input_enum: &ItemEnum,
+) -> syn::Result<(TokenStream2, Option<TokenStream2>)> {
+    if !cfg!(feature = "nightly") {
+        return Err(Error::new(
+            sig.span(),
+            "The `tait` option requires the `nightly` feature",
+        ));
+    }
+    let asyncness = match sig.asyncness.take() {
+        Some(asyncness) => asyncness,
+        None => {
+            return Err(Error::new(
+                sig.span(),
+                "The `tait` option is only supported for async methods",
+            ))
+        }
+    };
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new(
+            sig.generics.span(),
+            "The `tait` option is not supported for generic methods",
+        ));
+    }
+
+    // the future captures all of the arguments, so they're tied to the lifetime of the future
+    let lifetime = Lifetime::new("'__fut", Span::call_site());
+    sig.generics.params.push(syn::parse_quote!(#lifetime));
+    let mut elided = ElidedLifetimes(lifetime.clone());
+    for input in &mut sig.inputs {
+        elided.visit_fn_arg_mut(input);
+    }
+
+    // construct the type alias
+    let output = match &sig.output {
+        ReturnType::Default => quote::quote!(()),
+        ReturnType::Type(_, ty) => ty.to_token_stream(),
+    };
+    let enum_ident = &input_enum.ident;
+    let alias_ident = Ident::new(
+        &format!(
+            "{}{}Future",
+            enum_ident.unraw(),
+            sig.ident.unraw().to_string().to_upper_camel_case()
+        ),
+        sig.ident.span(),
+    );
+    let mut alias_generics = input_enum.generics.clone();
+    alias_generics
+        .params
+        .insert(0, syn::parse_quote!(#lifetime));
+    let (alias_impl_generics, alias_ty_generics, alias_where_clause) =
+        alias_generics.split_for_impl();
+    let alias = quote::quote! {
+        #vis type #alias_ident #alias_impl_generics #alias_where_clause
+            = impl ::core::future::Future<Output = #output> + #lifetime;
+    };
+    sig.output = syn::parse_quote!(-> #alias_ident #alias_ty_generics);
+
+    let method = quote::quote! {
+        #[define_opaque(#alias_ident)]
+        #method_attrs
+        #vis #sig {
+            #asyncness move {
+                #body
+            }
+        }
+    };
+    Ok((method, Some(alias)))
+}
+
+/// Replaces elided and anonymous reference lifetimes with the given lifetime.
+struct ElidedLifetimes(Lifetime);
+
+impl VisitMut for ElidedLifetimes {
+    fn visit_receiver_mut(&mut self, receiver: &mut Receiver) {
+        if let Some((_, lifetime)) = &mut receiver.reference {
+            match lifetime {
+                Some(lifetime) => self.visit_lifetime_mut(lifetime),
+                None => *lifetime = Some(self.0.clone()),
+            }
+        }
+        syn::visit_mut::visit_receiver_mut(self, receiver);
+    }
+
+    fn visit_type_reference_mut(&mut self, reference: &mut TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(self.0.clone());
+        }
+        syn::visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = self.0.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(arg: TokenStream2) -> String {
+        match super::super::parse_args::<Args>(
+            arg,
+            "Expected a list of method signatures, optionally preceded by options",
+        ) {
+            Ok(_) => panic!("the arguments were parsed"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn unexpected_token() {
+        assert_eq!(
+            "Expected a list of method signatures, optionally preceded by options: expected identifier",
+            parse_error(quote::quote! {
+                fn len(&self) -> usize;
+                fn 3
+            })
+        );
+    }
+
+    #[test]
+    fn method_body() {
+        assert_eq!(
+            "Method bodies are generated, provide only the signature",
+            parse_error(quote::quote! {
+                fn len(&self) -> usize { 0 }
+                fn is_empty(&self) -> bool
+            })
+        );
+    }
+
+    #[test]
+    fn duplicate_method() {
+        assert_eq!(
+            "The method `len` is listed more than once",
+            parse_error(quote::quote! {
+                fn len(&self) -> usize
+                fn is_empty(&self) -> bool
+                fn len(&self) -> usize
+            })
+        );
+        // methods of different traits are implemented in separate impls
+        assert!(syn::parse2::<Args>(quote::quote!(trait_impls, {
+            fn len(&self) -> usize in First
+            fn len(&self) -> usize in Second
+        }))
+        .is_ok());
+    }
+
+    #[test]
+    fn tagged_trait_impls() {
+        assert_eq!(
+            "The `tagged` option is not supported with `trait_impls`",
+            parse_error(quote::quote!(trait_impls, {
+                #[impl_enum(tagged)]
+                fn len(&self) -> usize in Len
+            }))
+        );
+        // the inherent methods are unaffected
+        assert!(syn::parse2::<Args>(quote::quote!(trait_impls, {
+            #[impl_enum(tagged)]
+            fn len(&self) -> usize
+        }))
+        .is_ok());
+    }
+
+    #[test]
+    fn tait_trait_impls() {
+        assert_eq!(
+            "The `tait` option is not supported with `trait_impls`",
+            parse_error(quote::quote!(trait_impls, {
+                #[impl_enum(tait)]
+                async fn len(&self) -> usize in Len
+            }))
+        );
+    }
+
+    #[test]
+    fn renamed_trait_method() {
+        assert_eq!(
+            "Methods in trait impls must have the name of the trait's method",
+            parse_error(quote::quote!(trait_impls, {
+                fn write(&mut self, s: &str) -> std::fmt::Result = write_str in Write
+            }))
+        );
+    }
+
+    fn method_error(field: Option<Member>, input_enum: &str) -> Error {
+        let method: Method = syn::parse_quote!(fn len(&self) -> usize);
+        let input_enum: ItemEnum = syn::parse_str(input_enum).unwrap();
+        match make_method(method, field.as_ref(), &quote::quote!(::std), &input_enum) {
+            Ok(_) => panic!("the method was made"),
+            Err(err) => err,
+        }
+    }
+
+    /// The error is reported at the second attribute.
+    #[test]
+    fn delegate_marked_twice() {
+        let err = method_error(
+            None,
+            "enum Buffers {
+                Two(#[impl_enum(delegate)] Vec<u8>, #[impl_enum(delegate)] Vec<u8>),
+            }",
+        );
+        assert_eq!(
+            "Only one field of the variant `Two` can be marked as the delegate",
+            err.to_string()
+        );
+        assert_eq!(
+            (2, 52),
+            (err.span().start().line, err.span().start().column)
+        );
+    }
+
+    #[test]
+    fn delegate_unknown_field() {
+        let err = method_error(
+            None,
+            "enum Source {
+                #[impl_enum(to = stream)]
+                File { path: String, reader: Vec<u8> },
+            }",
+        );
+        assert_eq!(
+            "The variant `File` has no field `stream`, the available fields are `path`, `reader`",
+            err.to_string()
+        );
+        assert_eq!(
+            (2, 16),
+            (err.span().start().line, err.span().start().column)
+        );
+    }
+
+    #[test]
+    fn field_out_of_range() {
+        let err = method_error(
+            Some(syn::parse_quote!(1)),
+            "enum Buffers {
+                Two(u8, Vec<u8>),
+                One(Vec<u8>),
+            }",
+        );
+        assert_eq!(
+            "The variant `One` has 1 field(s), so there is no field at index 1",
+            err.to_string()
+        );
+    }
+
+    /// The messages of the macro's own errors are not prefixed.
+    #[test]
+    fn unrecognized_option() {
+        assert_eq!(
+            "Unrecognized option",
+            parse_error(quote::quote!(unknown, { fn len(&self) -> usize }))
+        );
+    }
+
+    /// Each variant gets its own check over its field type, so that an error is reported at the variant.
+    #[test]
+    fn checks_every_variant() {
+        let method: Method = syn::parse_quote!(fn name(&self) -> String in Named);
+        let input_enum: ItemEnum = syn::parse_quote! {
+            enum Shape {
+                Square(Square),
+                Circle { circle: Circle },
+                #[impl_enum(skip)]
+                Empty,
+            }
+        };
+        let checks = make_checks(&method, None, &input_enum).unwrap();
+        assert_eq!(
+            vec![
+                "let _ = < Square as Named > :: name ;",
+                "let _ = < Circle as Named > :: name ;",
+            ],
+            checks.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+    }
+
+    /// The only variant of an enum is destructured with `let` instead of a `match`.
+    #[test]
+    fn single_variant() {
+        let method: Method = syn::parse_quote!(fn len(&self) -> usize);
+        let input_enum: ItemEnum = syn::parse_quote! {
+            enum Wrapper {
+                Vec(Vec<u8>),
+            }
+        };
+        let (method, _) = make_method(method, None, &quote::quote!(::std), &input_enum).unwrap();
+        assert_eq!(
+            quote::quote! {
+                fn len(&self) -> usize {
+                    {
+                        let Self::Vec { 0: __first, .. } = self;
+                        (__first).len()
+                    }
+                }
+            }
+            .to_string(),
+            method.to_string()
+        );
+    }
 }
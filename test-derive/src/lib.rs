@@ -0,0 +1,58 @@
+//! A custom derive with a helper attribute for testing the macros of impl-enum together with other derives.
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Implements `VARIANT_COUNT` for the enum, counting the variants that aren't annotated with `#[count(skip)]`.
+#[proc_macro_derive(VariantCount, attributes(count))]
+pub fn variant_count(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let enum_ident = loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "enum" => match tokens.next() {
+                Some(TokenTree::Ident(ident)) => break ident,
+                _ => panic!("expected the name of the enum"),
+            },
+            Some(_) => continue,
+            None => panic!("expected an enum"),
+        }
+    };
+    let variants = tokens
+        .find_map(|token| match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                Some(group.stream())
+            }
+            _ => None,
+        })
+        .expect("expected the variants of the enum");
+
+    // each variant is preceded by its attributes and followed by a comma
+    let mut count = 0;
+    let mut skipped = false;
+    let mut empty = true;
+    let mut variant_tokens = variants.into_iter().peekable();
+    while let Some(token) = variant_tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                if let Some(TokenTree::Group(attr)) = variant_tokens.next() {
+                    let attr = attr.stream().to_string().replace(' ', "");
+                    skipped |= attr == "count(skip)";
+                }
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                if !empty && !skipped {
+                    count += 1;
+                }
+                skipped = false;
+                empty = true;
+            }
+            _ => empty = false,
+        }
+    }
+    if !empty && !skipped {
+        count += 1;
+    }
+
+    format!("impl {enum_ident} {{ const VARIANT_COUNT: usize = {count}; }}")
+        .parse()
+        .unwrap()
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "as_dyn")]
+
+trait MyHttpClient {
+    fn get(&mut self, url: &str) -> String;
+}
+
+struct Mock(Vec<String>);
+struct Real;
+
+impl MyHttpClient for Mock {
+    fn get(&mut self, url: &str) -> String {
+        self.0.push(url.to_string());
+        "mock".to_string()
+    }
+}
+
+impl MyHttpClient for Real {
+    fn get(&mut self, url: &str) -> String {
+        format!("GET {url}")
+    }
+}
+
+#[impl_enum::as_dyn(flags(rc), MyHttpClient as client, std::fmt::Debug + Send as debug)]
+#[derive(Debug)]
+enum Client {
+    Mock(Mock),
+    Real(Real),
+}
+
+impl std::fmt::Debug for Mock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mock")
+    }
+}
+
+impl std::fmt::Debug for Real {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Real")
+    }
+}
+
+#[test]
+fn alias() {
+    let mut mock = Client::Mock(Mock(vec![]));
+    assert_eq!("mock", mock.as_client_mut().get("a"));
+    assert_eq!("Mock", format!("{:?}", mock.as_debug()));
+    let mut client = mock.into_client();
+    assert_eq!("mock", client.get("b"));
+
+    let mut real = Client::Real(Real);
+    assert_eq!("GET c", real.as_client_mut().get("c"));
+    assert_eq!("Real", format!("{:?}", real.into_rc_debug()));
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "as_dyn")]
+
+use std::sync::Arc;
+
+trait Greet {
+    fn greet(&self) -> String;
+}
+
+struct English;
+struct Finnish(&'static str);
+
+impl Greet for English {
+    fn greet(&self) -> String {
+        "hello".to_string()
+    }
+}
+
+impl Greet for Finnish {
+    fn greet(&self) -> String {
+        format!("moi {}", self.0)
+    }
+}
+
+#[impl_enum::as_dyn(flags(arc), Greet)]
+enum Greeter {
+    English(English),
+    Finnish { finnish: Finnish },
+}
+
+#[test]
+fn arc() {
+    let english: Arc<dyn Greet> = Greeter::English(English).into_arc_dyn_greet();
+    assert_eq!("hello", english.greet());
+    let finnish = Greeter::Finnish {
+        finnish: Finnish("maailma"),
+    }
+    .into_arc_dyn_greet();
+    let shared = Arc::clone(&finnish);
+    assert_eq!("moi maailma", shared.greet());
+    assert_eq!(2, Arc::strong_count(&finnish));
+    assert_eq!("hello", Greeter::English(English).as_dyn_greet().greet());
+}
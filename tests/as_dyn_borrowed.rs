@@ -0,0 +1,34 @@
+#![cfg(feature = "as_dyn")]
+
+use std::fmt::Display;
+
+// the trait objects borrow from the data of the enum
+#[impl_enum::as_dyn(flags(rc), Display)]
+enum Label<'a> {
+    Borrowed(&'a str),
+    Owned(String),
+    Both { prefix: &'a String },
+}
+
+fn describe(label: Label<'_>) -> String {
+    label.into_dyn_display().to_string()
+}
+
+#[test]
+fn borrowed() {
+    let text = String::from("borrowed");
+    let borrowed = Label::Borrowed(&text);
+    assert_eq!("borrowed", borrowed.as_dyn_display().to_string());
+    let boxed: Box<dyn Display + '_> = borrowed.into_dyn_display();
+    assert_eq!("borrowed", boxed.to_string());
+    assert_eq!("owned", describe(Label::Owned("owned".to_string())));
+    assert_eq!("borrowed", describe(Label::Both { prefix: &text }));
+    assert_eq!(
+        "borrowed",
+        Label::Borrowed(&text).into_rc_dyn_display().to_string()
+    );
+
+    // an enum without borrowed data still converts into a 'static trait object
+    let owned: Box<dyn Display> = Label::Owned("static".to_string()).into_dyn_display();
+    assert_eq!("static", owned.to_string());
+}
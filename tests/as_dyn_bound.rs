@@ -0,0 +1,45 @@
+#![cfg(feature = "as_dyn")]
+
+use std::{fmt::Debug, io::Write};
+
+#[derive(Debug)]
+struct Stub;
+
+impl Write for Stub {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// the enum itself does not require `T: Write`
+#[impl_enum::as_dyn(flags(bound), Write, Debug)]
+#[derive(Debug)]
+enum E<T> {
+    A(T),
+    B(Stub),
+}
+
+#[test]
+fn bound() {
+    let mut a = E::<Vec<u8>>::A(vec![]);
+    a.as_dyn_write_mut().write_all(b"hello").unwrap();
+    assert_eq!(
+        "[104, 101, 108, 108, 111]",
+        format!("{:?}", a.as_dyn_debug())
+    );
+
+    let mut b = E::<Vec<u8>>::B(Stub);
+    assert_eq!(3, b.as_dyn_write_mut().write(b"abc").unwrap());
+    assert_eq!("Stub", format!("{:?}", b.into_dyn_debug()));
+}
+
+// the enum is usable without the bound where the dyn functionality is not used
+#[test]
+fn unbounded() {
+    struct NotWrite;
+    let e = E::<NotWrite>::A(NotWrite);
+    assert!(matches!(e, E::A(NotWrite)));
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "as_dyn")]
+
+use std::{
+    io::{self, Cursor, Read, Write},
+    sync::{Arc, RwLock},
+    thread,
+};
+
+// trait objects can only have one trait besides auto traits
+#[impl_enum::as_dyn(flags(arc), Write + Send, Read + Send + Sync)]
+enum Stream {
+    Cursor(Cursor<Vec<u8>>),
+    Empty(io::Empty),
+}
+
+#[impl_enum::as_dyn(flags(lock), Write + Send + Sync)]
+enum Shared {
+    Vec(RwLock<Vec<u8>>),
+}
+
+#[test]
+fn compound() {
+    let mut cursor = Stream::Cursor(Cursor::new(vec![]));
+    let writer: &mut (dyn Write + Send) = cursor.as_dyn_write_send_mut();
+    writer.write_all(b"abc").unwrap();
+    let reader = cursor.as_dyn_read_send_sync_mut();
+    let mut buf = vec![];
+    reader.read_to_end(&mut buf).unwrap();
+    assert!(buf.is_empty());
+
+    let mut writer = Stream::Empty(io::empty()).into_dyn_write_send();
+    let handle = thread::spawn(move || writer.write(b"abc").unwrap());
+    assert_eq!(3, handle.join().unwrap());
+
+    let reader = Stream::Cursor(Cursor::new(vec![1, 2])).into_arc_dyn_read_send_sync();
+    let handle = thread::spawn(move || Arc::strong_count(&reader));
+    assert_eq!(1, handle.join().unwrap());
+
+    let shared = Shared::Vec(RwLock::new(vec![]));
+    shared
+        .write_dyn_write_send_sync()
+        .write_all(b"lock")
+        .unwrap();
+    match shared {
+        Shared::Vec(vec) => assert_eq!(b"lock", vec.into_inner().unwrap().as_slice()),
+    }
+}
+
+trait Job {
+    fn run(&self) -> u32;
+}
+
+struct Add(u32, u32);
+struct Double(u32);
+
+impl Job for Add {
+    fn run(&self) -> u32 {
+        self.0 + self.1
+    }
+}
+
+impl Job for Double {
+    fn run(&self) -> u32 {
+        self.0 * 2
+    }
+}
+
+// the markers apply to every generated method
+#[impl_enum::as_dyn(flags(arc), Job + Send + Sync)]
+enum AnyJob {
+    Add(Add),
+    Double(Double),
+}
+
+#[test]
+fn send_sync() {
+    let job: Box<dyn Job + Send + Sync> = AnyJob::Add(Add(1, 2)).into_dyn_job_send_sync();
+    let handle = thread::spawn(move || job.run());
+    assert_eq!(3, handle.join().unwrap());
+
+    let job = AnyJob::Double(Double(2)).into_arc_dyn_job_send_sync();
+    let shared = Arc::clone(&job);
+    let handle = thread::spawn(move || shared.run());
+    assert_eq!(4, handle.join().unwrap());
+
+    let job = AnyJob::Double(Double(3));
+    let job: &(dyn Job + Send + Sync) = job.as_dyn_job_send_sync();
+    thread::scope(|scope| {
+        assert_eq!(6, scope.spawn(|| job.run()).join().unwrap());
+    });
+}
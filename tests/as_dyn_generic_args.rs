@@ -0,0 +1,18 @@
+#![cfg(feature = "as_dyn")]
+
+// the names of the generic arguments keep the method names apart
+#[impl_enum::as_dyn(AsRef<str>, AsRef<[u8]>)]
+enum Text {
+    String(String),
+    Str(&'static str),
+}
+
+#[test]
+fn generic_args() {
+    let string = Text::String("string".to_string());
+    assert_eq!("string", string.as_dyn_as_ref_str().as_ref());
+    let str = Text::Str("str");
+    assert_eq!(b"str", str.as_dyn_as_ref_slice_u8().as_ref());
+    let bytes: Box<dyn AsRef<[u8]>> = string.into_dyn_as_ref_slice_u8();
+    assert_eq!(b"string", bytes.as_ref().as_ref());
+}
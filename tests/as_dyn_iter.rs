@@ -0,0 +1,56 @@
+#![cfg(feature = "as_dyn")]
+
+use std::{
+    fmt::Debug,
+    io::{Cursor, Write},
+};
+
+#[impl_enum::as_dyn(flags(iter), Write)]
+enum Sinks {
+    Buffers(Vec<Vec<u8>>),
+    Boxed { writers: Vec<Box<dyn Write>> },
+    Cursors([Cursor<Vec<u8>>; 2]),
+}
+
+#[test]
+fn iter() {
+    let mut buffers = Sinks::Buffers(vec![vec![], vec![1]]);
+    for writer in buffers.iter_dyn_write_mut() {
+        writer.write_all(b"hi").unwrap();
+    }
+    match &buffers {
+        Sinks::Buffers(buffers) => {
+            assert_eq!(&[vec![104, 105], vec![1, 104, 105]], buffers.as_slice())
+        }
+        _ => panic!(),
+    }
+
+    let mut boxed = Sinks::Boxed {
+        writers: vec![Box::new(vec![]), Box::new(Cursor::new(vec![]))],
+    };
+    assert_eq!(2, boxed.iter_dyn_write_mut().count());
+    for writer in boxed.iter_dyn_write_mut() {
+        writer.write_all(b"hi").unwrap();
+    }
+
+    let cursors = Sinks::Cursors([Cursor::new(vec![]), Cursor::new(vec![])]);
+    assert_eq!(2, cursors.iter_dyn_write().count());
+}
+
+#[impl_enum::as_dyn(flags(iter), Debug)]
+enum Values<'a> {
+    Vec(Vec<u8>),
+    Slice(&'a mut [&'a str]),
+}
+
+#[test]
+fn iter_slice() {
+    let debug = |values: &Values| {
+        values
+            .iter_dyn_debug()
+            .map(|debug| format!("{:?}", debug))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec!["1", "2"], debug(&Values::Vec(vec![1, 2])));
+    assert_eq!(vec!["\"a\""], debug(&Values::Slice(&mut ["a"])));
+}
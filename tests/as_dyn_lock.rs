@@ -0,0 +1,52 @@
+#![cfg(feature = "as_dyn")]
+
+use std::{fmt::Debug, io::Write, sync::RwLock};
+
+#[impl_enum::as_dyn(flags(lock), Debug, Write)]
+enum Shared {
+    Vec(RwLock<Vec<u8>>),
+    Sink { sink: RwLock<std::io::Sink> },
+}
+
+#[test]
+fn read() {
+    let shared = Shared::Vec(RwLock::new(vec![1, 2]));
+    let guard = shared.read_dyn_debug();
+    assert_eq!("[1, 2]", format!("{:?}", &*guard));
+    // multiple readers can hold the lock
+    let other = shared.read_dyn_debug();
+    assert_eq!(format!("{:?}", &*guard), format!("{:?}", &*other));
+}
+
+#[test]
+fn write() {
+    let shared = Shared::Vec(RwLock::new(vec![]));
+    shared.write_dyn_write().write_all(b"abc").unwrap();
+    assert_eq!("[97, 98, 99]", format!("{:?}", &*shared.read_dyn_debug()));
+
+    let sink = Shared::Sink {
+        sink: RwLock::new(std::io::sink()),
+    };
+    let mut guard = sink.write_dyn_write();
+    guard.write_all(b"abc").unwrap();
+    guard.flush().unwrap();
+}
+
+// the values in the locks may borrow data
+#[impl_enum::as_dyn(flags(lock), Debug)]
+enum Borrowed<'a> {
+    Slice(RwLock<&'a [u8]>),
+    Str { str: RwLock<&'a str> },
+}
+
+#[test]
+fn borrowed() {
+    let data = vec![1, 2];
+    let borrowed = Borrowed::Slice(RwLock::new(&data));
+    assert_eq!("[1, 2]", format!("{:?}", &*borrowed.read_dyn_debug()));
+    let text = String::from("text");
+    let borrowed = Borrowed::Str {
+        str: RwLock::new(&text),
+    };
+    assert_eq!("\"text\"", format!("{:?}", &*borrowed.write_dyn_debug()));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "as_dyn")]
+
+trait HTTPClient {}
+trait IOSource {}
+trait JSONValue {}
+trait XMLHttpRequest {}
+trait Utf8Decoder {}
+
+struct A;
+struct B;
+
+impl HTTPClient for A {}
+impl HTTPClient for B {}
+impl IOSource for A {}
+impl IOSource for B {}
+impl JSONValue for A {}
+impl JSONValue for B {}
+impl XMLHttpRequest for A {}
+impl XMLHttpRequest for B {}
+impl Utf8Decoder for A {}
+impl Utf8Decoder for B {}
+
+// acronyms are treated as words
+#[impl_enum::as_dyn(HTTPClient, IOSource, JSONValue, XMLHttpRequest, Utf8Decoder)]
+enum Enum {
+    A(A),
+    B { b: B },
+}
+
+#[test]
+fn names() {
+    let mut e = Enum::A(A);
+    let _: &dyn HTTPClient = e.as_dyn_http_client();
+    let _: &mut dyn HTTPClient = e.as_dyn_http_client_mut();
+    let _: &dyn IOSource = e.as_dyn_io_source();
+    let _: &dyn JSONValue = e.as_dyn_json_value();
+    let _: &dyn XMLHttpRequest = e.as_dyn_xml_http_request();
+    let _: &dyn Utf8Decoder = e.as_dyn_utf8_decoder();
+    let _: Box<dyn HTTPClient> = e.into_dyn_http_client();
+    let _: Box<dyn XMLHttpRequest> = Enum::B { b: B }.into_dyn_xml_http_request();
+}
+
+// traits named like the flags are used as traits outside of the flags group
+#[allow(non_camel_case_types)]
+trait iter {
+    fn name(&self) -> &'static str;
+}
+
+impl iter for A {
+    fn name(&self) -> &'static str {
+        "a"
+    }
+}
+
+#[impl_enum::as_dyn(flags(rc), iter)]
+enum Flagged {
+    A(A),
+}
+
+#[test]
+fn flag_named_trait() {
+    assert_eq!("a", Flagged::A(A).as_dyn_iter().name());
+    assert_eq!("a", Flagged::A(A).into_rc_dyn_iter().name());
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "as_dyn")]
+
+use std::rc::Rc;
+
+trait Area {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+struct Rectangle(u32, u32);
+
+impl Area for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+impl Area for Rectangle {
+    fn area(&self) -> u32 {
+        self.0 * self.1
+    }
+}
+
+#[impl_enum::as_dyn(flags(rc, arc), Area)]
+enum Shape {
+    Square(Square),
+    Rectangle(Rectangle),
+}
+
+#[test]
+fn rc() {
+    let square: Rc<dyn Area> = Shape::Square(Square(3)).into_rc_dyn_area();
+    let shared = Rc::clone(&square);
+    assert_eq!(9, shared.area());
+    assert_eq!(2, Rc::strong_count(&square));
+    assert_eq!(
+        6,
+        Shape::Rectangle(Rectangle(2, 3)).into_arc_dyn_area().area()
+    );
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "as_dyn")]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+#[impl_enum::as_dyn(flags(slice), Debug)]
+enum Value {
+    Number(i32),
+    Text { text: String },
+    Bytes(Vec<u8>),
+}
+
+#[test]
+fn slice() {
+    let values = vec![
+        Value::Number(1),
+        Value::Text {
+            text: "text".to_string(),
+        },
+        Value::Bytes(vec![2]),
+    ];
+    let debugs = Value::as_dyn_debug_slice(&values);
+    assert_eq!(3, debugs.len());
+    let formatted = debugs
+        .iter()
+        .map(|debug| format!("{:?}", debug))
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["1", "\"text\"", "[2]"], formatted);
+    assert!(Value::as_dyn_debug_slice(&[]).is_empty());
+}
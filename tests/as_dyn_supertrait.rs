@@ -0,0 +1,42 @@
+#![cfg(feature = "as_dyn")]
+
+use std::io::{Cursor, Read, Write};
+
+trait ReadWrite: Read + Write {}
+
+impl<T: Read + Write> ReadWrite for T {}
+
+#[impl_enum::as_dyn(Read, Write, ReadWrite)]
+enum Stream {
+    Cursor(Cursor<Vec<u8>>),
+    Other { cursor: Cursor<Vec<u8>> },
+}
+
+#[test]
+fn supertrait() {
+    let mut stream = Stream::Cursor(Cursor::new(vec![]));
+    stream.as_dyn_write_mut().write_all(b"hello").unwrap();
+    stream.as_dyn_read_write_mut().write_all(b" world").unwrap();
+    match &mut stream {
+        Stream::Cursor(cursor) => cursor.set_position(0),
+        Stream::Other { .. } => panic!(),
+    }
+    let mut buf = [0; 5];
+    stream.as_dyn_read_mut().read_exact(&mut buf).unwrap();
+    assert_eq!(b"hello", &buf);
+    let mut rest = String::new();
+    stream
+        .as_dyn_read_write_mut()
+        .read_to_string(&mut rest)
+        .unwrap();
+    assert_eq!(" world", rest);
+
+    let mut other = Stream::Other {
+        cursor: Cursor::new(b"other".to_vec()),
+    }
+    .into_dyn_read_write();
+    let mut all = String::new();
+    other.read_to_string(&mut all).unwrap();
+    other.write_all(b"!").unwrap();
+    assert_eq!("other", all);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "as_dyn")]
+
+mod shapes {
+    pub trait Area {
+        fn area(&self) -> u32;
+    }
+
+    pub struct Square(pub u32);
+
+    impl Area for Square {
+        fn area(&self) -> u32 {
+            self.0 * self.0
+        }
+    }
+
+    #[impl_enum::as_dyn(pub; flags(slice), Area)]
+    pub enum Shape {
+        Square(Square),
+    }
+
+    #[impl_enum::as_dyn(pub(crate); Area)]
+    pub enum CrateShape {
+        Square(Square),
+    }
+}
+
+use shapes::{CrateShape, Shape, Square};
+
+#[test]
+fn visibility() {
+    let mut shape = Shape::Square(Square(2));
+    assert_eq!(4, shape.as_dyn_area().area());
+    assert_eq!(4, shape.as_dyn_area_mut().area());
+    assert_eq!(1, Shape::as_dyn_area_slice(&[shape]).len());
+    assert_eq!(9, CrateShape::Square(Square(3)).into_dyn_area().area());
+}
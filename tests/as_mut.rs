@@ -0,0 +1,46 @@
+#![cfg(feature = "as_mut")]
+
+#[impl_enum::as_mut(str)]
+#[derive(Debug, PartialEq)]
+enum Text {
+    String(String),
+    Boxed { boxed: Box<str> },
+}
+
+#[impl_enum::as_mut(str, trait_impls)]
+#[impl_enum::as_ref(str, trait_impls)]
+enum Generic<S: AsRef<str> + AsMut<str>> {
+    Inner(S),
+    String(String),
+}
+
+fn uppercase<S: AsMut<str>>(mut s: S) -> S {
+    s.as_mut().make_ascii_uppercase();
+    s
+}
+
+#[test]
+fn as_mut() {
+    let mut text = Text::String("string".to_string());
+    text.as_mut_str().make_ascii_uppercase();
+    assert_eq!(Text::String("STRING".to_string()), text);
+
+    let mut text = Text::Boxed {
+        boxed: "boxed".into(),
+    };
+    text.as_mut_str().make_ascii_uppercase();
+    assert_eq!(
+        Text::Boxed {
+            boxed: "BOXED".into()
+        },
+        text
+    );
+}
+
+#[test]
+fn trait_impls() {
+    let mut generic = uppercase(Generic::<String>::String("string".to_string()));
+    assert_eq!("STRING", generic.as_mut());
+    let generic = uppercase(Generic::Inner("inner".to_string()));
+    assert_eq!("INNER", generic.as_ref());
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "as_ref")]
+
+use std::path::{Path, PathBuf};
+
+#[impl_enum::as_ref(str, [u8])]
+enum A {
+    String(String),
+    Str { s: &'static str },
+}
+
+#[impl_enum::as_ref(Path, std::ffi::OsStr)]
+enum B {
+    PathBuf(PathBuf),
+    String(String),
+}
+
+#[impl_enum::as_ref(str)]
+enum C<'a> {
+    Borrowed(&'a str),
+    Owned(String),
+}
+
+#[test]
+fn multiple_targets() {
+    let a = A::String("string".to_string());
+    assert_eq!("string", a.as_ref_str());
+    assert_eq!(b"string", a.as_ref_slice_u8());
+    let a = A::Str { s: "str" };
+    assert_eq!("str", a.as_ref_str());
+    assert_eq!(b"str", a.as_ref_slice_u8());
+}
+
+#[test]
+fn paths() {
+    let b = B::PathBuf(PathBuf::from("dir/file"));
+    assert_eq!(Path::new("dir/file"), b.as_ref_path());
+    let b = B::String("file".to_string());
+    assert_eq!("file", b.as_ref_os_str());
+}
+
+#[test]
+fn borrowed() {
+    let owned = "borrowed".to_string();
+    let c = C::Borrowed(&owned);
+    assert_eq!("borrowed", c.as_ref_str());
+    let c = C::Owned("owned".to_string());
+    assert_eq!("owned", c.as_ref_str());
+}
+
+#[impl_enum::as_ref(str, as_mut)]
+enum D {
+    String(String),
+    Boxed(Box<str>),
+}
+
+#[test]
+fn as_mut() {
+    let mut d = D::String("string".to_string());
+    d.as_mut_str().make_ascii_uppercase();
+    assert_eq!("STRING", d.as_ref_str());
+    let mut d = D::Boxed("boxed".into());
+    d.as_mut_str().make_ascii_uppercase();
+    assert_eq!("BOXED", d.as_ref_str());
+}
+
+#[impl_enum::as_ref(str, Path, trait_impls)]
+enum E<'a> {
+    Borrowed(&'a str),
+    Owned { owned: String },
+}
+
+#[impl_enum::as_ref([u8], as_mut, trait_impls)]
+enum F {
+    Vec(Vec<u8>),
+    Array([u8; 2]),
+}
+
+fn len(s: impl AsRef<str>) -> usize {
+    s.as_ref().len()
+}
+
+fn zero(mut bytes: impl AsMut<[u8]>) -> Vec<u8> {
+    bytes.as_mut().fill(0);
+    bytes.as_mut().to_vec()
+}
+
+#[test]
+fn trait_impls() {
+    let borrowed = "borrowed".to_string();
+    assert_eq!(8, len(E::Borrowed(&borrowed)));
+    assert_eq!(
+        5,
+        len(E::Owned {
+            owned: "owned".to_string()
+        })
+    );
+    let path: &Path = E::Borrowed("dir/file").as_ref();
+    assert_eq!(Path::new("dir/file"), path);
+
+    assert_eq!(vec![0, 0, 0], zero(F::Vec(vec![1, 2, 3])));
+    let f = F::Array([1, 2]);
+    assert_eq!(&[1, 2], AsRef::<[u8]>::as_ref(&f));
+    assert_eq!(vec![0, 0], zero(f));
+}
+
+#[impl_enum::as_ref(str, as_mut)]
+#[impl_enum::as_ref(str, as_mut, trait_impls)]
+enum Never {}
+
+#[test]
+fn empty_enum() {
+    fn assert_impls(never: Option<Never>) {
+        if let Some(mut never) = never {
+            let _ = never.as_ref_str();
+            let _ = never.as_mut_str();
+            let _: &str = never.as_ref();
+            let _: &mut str = never.as_mut();
+        }
+    }
+    assert_impls(None);
+}
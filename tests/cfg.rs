@@ -0,0 +1,82 @@
+#![cfg(all(
+    feature = "as_ref",
+    feature = "map_to",
+    feature = "take_or_default",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "with_field",
+    feature = "inner_ref"
+))]
+
+#[derive(Debug, PartialEq)]
+enum Target {
+    Text(String),
+}
+
+#[impl_enum::as_ref(str, cfg = test)]
+#[impl_enum::map_to(Target, cfg = test)]
+#[impl_enum::take_or_default(cfg = test)]
+#[impl_enum::variant_names(from_name, cfg = test)]
+#[impl_enum::variant_helpers(cfg = test)]
+#[impl_enum::with_field(cfg = test)]
+#[impl_enum::inner_ref(cfg = test)]
+#[derive(Debug, PartialEq)]
+enum Source {
+    Text(String),
+}
+
+#[impl_enum::variant_names(cfg = not(test))]
+enum Excluded {
+    Unit,
+}
+
+// a method with the same name only compiles if the generated one is excluded
+impl Excluded {
+    fn variant_name(&self) -> &'static str {
+        "excluded"
+    }
+}
+
+#[test]
+fn included() {
+    let source = Source::Text("text".to_string());
+    assert_eq!("text", source.as_ref_str());
+    assert!(source.is_text());
+    assert_eq!(4, source.with_field(|text| text.len()));
+    assert_eq!("text", source.inner());
+    assert_eq!("Text", source.variant_name());
+    assert_eq!(&["Text"], Source::VARIANT_NAMES);
+    assert_eq!(
+        Some(Source::Text("a".to_string())),
+        Source::from_name("Text", "a".to_string())
+    );
+    assert_eq!(Target::Text("text".to_string()), source.to_target());
+    assert_eq!("b", Source::Text("b".to_string()).take_or_default());
+}
+
+#[impl_enum::variant_helpers(cfg = not(test))]
+#[impl_enum::inner_ref(cfg = not(test))]
+enum ExcludedAccessors {
+    Value(u8),
+}
+
+impl ExcludedAccessors {
+    fn is_value(&self) -> bool {
+        false
+    }
+    fn inner(&self) -> u8 {
+        let Self::Value(value) = self;
+        value + 1
+    }
+}
+
+#[test]
+fn excluded_accessors() {
+    assert!(!ExcludedAccessors::Value(1).is_value());
+    assert_eq!(2, ExcludedAccessors::Value(1).inner());
+}
+
+#[test]
+fn excluded() {
+    assert_eq!("excluded", Excluded::Unit.variant_name());
+}
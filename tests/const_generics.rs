@@ -0,0 +1,49 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+
+use std::fmt::Debug;
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn as_ref(&self) -> &[u8] in AsRef<[u8]>
+    fn clone(&self) -> Self
+}]
+#[impl_enum::as_dyn(Debug, AsRef<[u8]>)]
+enum Buf<const N: usize> {
+    Stack([u8; N]),
+    Heap(Vec<u8>),
+}
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+enum Mixed<'a, T: Debug, const N: usize>
+where
+    T: Clone,
+{
+    Array([T; N]),
+    Slice(&'a [T]),
+}
+
+#[test]
+fn const_generics() {
+    let stack = Buf::Stack([1, 2, 3]);
+    assert_eq!(3, stack.len());
+    assert_eq!(&[1, 2, 3], stack.as_ref());
+    assert!(matches!(stack.clone(), Buf::Stack([1, 2, 3])));
+    assert_eq!("[1, 2, 3]", format!("{:?}", stack.as_dyn_debug()));
+    assert_eq!(&[1, 2, 3], stack.as_dyn_as_ref_slice_u8().as_ref());
+
+    let heap = Buf::<0>::Heap(vec![4]);
+    assert_eq!(1, heap.len());
+    assert_eq!("[4]", format!("{:?}", heap.into_dyn_debug()));
+}
+
+#[test]
+fn mixed() {
+    let array = Mixed::<_, 2>::Array(['a', 'b']);
+    assert_eq!(2, array.len());
+    assert_eq!("['a', 'b']", format!("{:?}", array.as_dyn_debug()));
+    let slice = Mixed::<_, 2>::Slice(&['c']);
+    assert_eq!(1, slice.len());
+}
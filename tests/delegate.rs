@@ -0,0 +1,94 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn push(&mut self, value: u8)
+}]
+#[impl_enum::as_dyn(Debug)]
+enum Marked {
+    Tuple(u32, &'static str, #[impl_enum(delegate)] Vec<u8>),
+    Named {
+        id: u32,
+        #[impl_enum(delegate)]
+        data: Vec<u8>,
+    },
+    // the first field is used if none are marked
+    First(Vec<u8>, u32),
+}
+
+#[test]
+fn marked_field() {
+    let mut tuple = Marked::Tuple(1, "tuple", vec![1]);
+    tuple.push(2);
+    assert_eq!(2, tuple.len());
+    assert_eq!("[1, 2]", format!("{:?}", tuple.as_dyn_debug()));
+
+    let mut named = Marked::Named {
+        id: 2,
+        data: vec![],
+    };
+    named.push(1);
+    assert_eq!(1, named.len());
+    assert_eq!("[1]", format!("{:?}", named.into_dyn_debug()));
+
+    let first = Marked::First(vec![1, 2, 3], 4);
+    assert_eq!(3, first.len());
+    assert_eq!("[1, 2, 3]", format!("{:?}", first.as_dyn_debug()));
+}
+
+// the marked field takes precedence over the field option
+#[impl_enum::with_methods(field = 1, {
+    fn len(&self) -> usize
+})]
+enum Override {
+    Default(u8, Vec<u8>),
+    Marked(#[impl_enum(delegate)] String, Vec<u8>),
+}
+
+#[test]
+fn override_field_option() {
+    assert_eq!(2, Override::Default(0, vec![1, 2]).len());
+    assert_eq!(3, Override::Marked("abc".to_string(), vec![]).len());
+}
+
+// heterogeneous variants name their delegate fields
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+enum Source {
+    #[impl_enum(to = reader)]
+    File {
+        path: String,
+        reader: Vec<u8>,
+    },
+    #[impl_enum(to = stream)]
+    Socket {
+        address: String,
+        stream: Vec<u8>,
+    },
+    Pipe(u32, #[impl_enum(to)] Vec<u8>),
+}
+
+#[test]
+fn to() {
+    let file = Source::File {
+        path: "path".to_string(),
+        reader: vec![1],
+    };
+    assert_eq!(1, file.len());
+    assert_eq!("[1]", format!("{:?}", file.as_dyn_debug()));
+
+    let socket = Source::Socket {
+        address: "address".to_string(),
+        stream: vec![1, 2],
+    };
+    assert_eq!(2, socket.len());
+
+    let pipe = Source::Pipe(0, vec![1, 2, 3]);
+    assert_eq!(3, pipe.len());
+    assert_eq!("[1, 2, 3]", format!("{:?}", pipe.as_dyn_debug()));
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "delegate_trait")]
+
+use std::fmt::{self, Write};
+
+struct Upper(String);
+
+impl Write for Upper {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.push_str(&s.to_uppercase());
+        Ok(())
+    }
+}
+
+#[impl_enum::delegate_trait(Write {
+    fn write_str(&mut self, s: &str) -> fmt::Result;
+})]
+enum Output {
+    String(String),
+    Upper { upper: Upper },
+}
+
+#[test]
+fn fmt_write() {
+    let mut string = Output::String(String::new());
+    write!(string, "a-{}", 1).unwrap();
+    let mut upper = Output::Upper {
+        upper: Upper(String::new()),
+    };
+    write!(upper, "a-{}", 1).unwrap();
+    match (string, upper) {
+        (Output::String(string), Output::Upper { upper }) => {
+            assert_eq!("a-1", string);
+            assert_eq!("A-1", upper.0);
+        }
+        _ => panic!(),
+    }
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+    fn name(&self) -> String {
+        "shape".to_string()
+    }
+}
+
+struct Square(f64);
+struct Circle(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+}
+
+// the methods of the trait can be delegated selectively
+#[impl_enum::delegate_trait(Shape {
+    fn area(&self) -> f64
+})]
+enum AnyShape {
+    Square(Square),
+    Circle(Circle),
+}
+
+fn describe(shape: &dyn Shape) -> String {
+    format!("{} {:.0}", shape.name(), shape.area())
+}
+
+#[test]
+fn default_methods() {
+    assert_eq!("shape 4", describe(&AnyShape::Square(Square(2.0))));
+    // the default implementation is used for the enum instead of the field's
+    assert_eq!("shape 3", describe(&AnyShape::Circle(Circle(1.0))));
+}
@@ -0,0 +1,115 @@
+#![cfg(all(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "variant_names"
+))]
+#![allow(dead_code)]
+
+use impl_enum_test_derive::VariantCount;
+use std::fmt::Debug;
+
+// derives before the macros see the original enum
+#[derive(Debug, Clone, PartialEq)]
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+enum Before {
+    Vec(Vec<u8>),
+    String { string: String },
+}
+
+// derives after the macros see the re-emitted enum
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+enum After {
+    Vec(Vec<u8>),
+    String { string: String },
+}
+
+// derive helper attributes on variants are kept
+#[derive(Debug, Default, PartialEq)]
+#[impl_enum::variant_names]
+enum Helper {
+    #[default]
+    Empty,
+    Full(u8),
+}
+
+// a custom derive sees its helper attributes and the variants skipped by the macros
+#[derive(VariantCount)]
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+enum CustomBefore {
+    Vec(Vec<u8>),
+    #[count(skip)]
+    String(String),
+    #[impl_enum(skip)]
+    Empty,
+}
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+#[derive(VariantCount)]
+enum CustomAfter {
+    #[count(skip)]
+    Vec(Vec<u8>),
+    String {
+        string: String,
+    },
+    #[impl_enum(skip)]
+    Empty,
+}
+
+#[test]
+fn before() {
+    let before = Before::Vec(vec![1, 2]);
+    assert_eq!(before.clone(), before);
+    assert_eq!(2, before.len());
+    assert_eq!("Vec([1, 2])", format!("{:?}", before));
+    assert_eq!("[1, 2]", format!("{:?}", before.as_dyn_debug()));
+}
+
+#[test]
+fn after() {
+    let after = After::String {
+        string: "abc".to_string(),
+    };
+    assert_eq!(after.clone(), after);
+    assert_eq!(3, after.len());
+    assert_eq!("String { string: \"abc\" }", format!("{:?}", after));
+    assert_eq!("\"abc\"", format!("{:?}", after.as_dyn_debug()));
+}
+
+#[test]
+fn helper() {
+    assert_eq!(Helper::Empty, Helper::default());
+    assert_eq!("Empty", Helper::default().variant_name());
+    assert_eq!("Full", Helper::Full(0).variant_name());
+}
+
+#[test]
+fn custom() {
+    assert_eq!(2, CustomBefore::VARIANT_COUNT);
+    assert_eq!(2, CustomBefore::Vec(vec![1, 2]).len());
+    assert_eq!(
+        "\"abc\"",
+        format!(
+            "{:?}",
+            CustomBefore::String("abc".to_string()).as_dyn_debug()
+        )
+    );
+    assert_eq!(2, CustomAfter::VARIANT_COUNT);
+    assert_eq!(1, CustomAfter::Vec(vec![1]).len());
+    let after = CustomAfter::String {
+        string: "abc".to_string(),
+    };
+    assert_eq!("\"abc\"", format!("{:?}", after.as_dyn_debug()));
+}
@@ -0,0 +1,23 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+
+use std::{fmt::Debug, io};
+
+#[impl_enum::as_dyn(Debug)]
+#[impl_enum::with_methods {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    fn flush(&mut self) -> io::Result<()>
+}]
+enum Never {}
+
+#[test]
+fn empty_enum() {
+    fn assert_methods(never: Option<Never>) {
+        if let Some(mut never) = never {
+            let _ = never.write(b"never");
+            let _ = never.flush();
+            let _ = never.as_dyn_debug();
+            let _ = never.into_dyn_debug();
+        }
+    }
+    assert_methods(None);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "from_variants")]
+#![allow(dead_code)]
+
+use std::io::{Cursor, Write};
+
+#[impl_enum::from_variants]
+enum Writer {
+    Cursor(Cursor<Vec<u8>>),
+    File {
+        file: std::fs::File,
+    },
+    // the field type is the same as that of `Cursor`
+    #[impl_enum(skip)]
+    Other(Cursor<Vec<u8>>),
+}
+
+#[impl_enum::from_variants]
+enum Generic<T> {
+    Value(T),
+    List(Vec<T>),
+}
+
+#[test]
+fn from_variants() {
+    let cursor = Cursor::new(vec![]);
+    let w: Writer = cursor.into();
+    match w {
+        Writer::Cursor(mut cursor) => cursor.write_all(b"cursor").unwrap(),
+        Writer::File { .. } | Writer::Other(_) => panic!(),
+    }
+
+    assert!(matches!(Generic::<i32>::from(1), Generic::Value(1)));
+    assert!(matches!(Generic::<i32>::from(vec![1]), Generic::List(_)));
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "impl_display")]
+#![allow(dead_code)]
+
+use std::fmt::Display;
+
+#[impl_enum::impl_display]
+enum Value<T: Display> {
+    Integer(i64),
+    Text { text: String },
+    Other(T, u8),
+}
+
+#[impl_enum::impl_display]
+enum Single {
+    Float(f64),
+}
+
+// the arms of deprecated variants don't warn
+#[impl_enum::impl_display]
+enum Deprecated {
+    #[deprecated]
+    Old(u8),
+    New(u8),
+}
+
+#[test]
+fn display() {
+    assert_eq!("1", format!("{}", Value::<bool>::Integer(1)));
+    let text = Value::<bool>::Text {
+        text: "text".to_string(),
+    };
+    assert_eq!("text", format!("{}", text));
+    assert_eq!("true", format!("{}", Value::Other(true, 0)));
+}
+
+#[test]
+fn formatter_flags() {
+    assert_eq!("  1", format!("{:>3}", Value::<bool>::Integer(1)));
+    assert_eq!("1.50", format!("{:.2}", Single::Float(1.5)));
+}
+
+#[test]
+fn deprecated() {
+    assert_eq!("2", Deprecated::New(2).to_string());
+}
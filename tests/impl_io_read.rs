@@ -0,0 +1,58 @@
+#![cfg(feature = "impl_io_read")]
+
+use std::io::{Cursor, IoSliceMut, Read};
+
+#[impl_enum::impl_io_read]
+enum Input<'a, R: Read> {
+    Cursor(Cursor<Vec<u8>>),
+    Slice { slice: &'a [u8] },
+    Other(R, u8),
+}
+
+fn read_dyn(src: &mut dyn Read) {
+    let mut buf = [0; 2];
+    src.read_exact(&mut buf).unwrap();
+    assert_eq!(b"ab", &buf);
+    let mut first = [0; 1];
+    let mut second = [0; 1];
+    let read = src
+        .read_vectored(&mut [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)])
+        .unwrap();
+    assert_eq!(2, read);
+    assert_eq!((b'c', b'd'), (first[0], second[0]));
+    assert_eq!(1, src.read(&mut buf[..1]).unwrap());
+    assert_eq!(b'e', buf[0]);
+    let mut rest = vec![];
+    assert_eq!(2, src.read_to_end(&mut rest).unwrap());
+    assert_eq!(b"fg", rest.as_slice());
+}
+
+#[test]
+fn cursor() {
+    let mut input: Input<std::io::Empty> = Input::Cursor(Cursor::new(b"abcdefg".to_vec()));
+    read_dyn(&mut input);
+}
+
+#[test]
+fn slice() {
+    let mut input: Input<std::io::Empty> = Input::Slice { slice: b"abcdefg" };
+    read_dyn(&mut input);
+
+    let mut input: Input<std::io::Empty> = Input::Slice { slice: b"string" };
+    let mut string = String::new();
+    input.read_to_string(&mut string).unwrap();
+    assert_eq!("string", string);
+}
+
+#[test]
+fn generic() {
+    let mut input = Input::Other(&b"abcdefg"[..], 0);
+    read_dyn(&mut input);
+    match input {
+        Input::Other(rest, index) => {
+            assert!(rest.is_empty());
+            assert_eq!(0, index);
+        }
+        _ => panic!(),
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "impl_io_write")]
+
+use std::io::{Cursor, IoSlice, Write};
+
+#[impl_enum::impl_io_write]
+enum Output<W: Write> {
+    Vec(Vec<u8>),
+    Cursor { cursor: Cursor<Vec<u8>> },
+    Other(W, u8),
+}
+
+fn write_dyn(dst: &mut dyn Write) {
+    dst.write_all(b"ab").unwrap();
+    assert_eq!(1, dst.write(b"c").unwrap());
+    write!(dst, "{}", 12).unwrap();
+    let written = dst
+        .write_vectored(&[IoSlice::new(b"x"), IoSlice::new(b"y")])
+        .unwrap();
+    assert_eq!(2, written);
+    dst.flush().unwrap();
+}
+
+#[test]
+fn vec() {
+    let mut output = Output::<std::io::Sink>::Vec(vec![]);
+    write_dyn(&mut output);
+    match output {
+        Output::Vec(vec) => assert_eq!(b"abc12xy", vec.as_slice()),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn cursor() {
+    let mut output = Output::<std::io::Sink>::Cursor {
+        cursor: Cursor::new(vec![]),
+    };
+    write_dyn(&mut output);
+    match output {
+        Output::Cursor { cursor } => assert_eq!(b"abc12xy", cursor.get_ref().as_slice()),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn generic() {
+    let mut output = Output::Other(vec![], 0);
+    write_dyn(&mut output);
+    match output {
+        Output::Other(vec, index) => {
+            assert_eq!(b"abc12xy", vec.as_slice());
+            assert_eq!(0, index);
+        }
+        _ => panic!(),
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "impl_iterator")]
+
+#[impl_enum::impl_iterator(Item = u8)]
+enum Bytes {
+    Vec(std::vec::IntoIter<u8>),
+    Array { array: std::array::IntoIter<u8, 2> },
+}
+
+#[impl_enum::impl_iterator(Item = T::Item)]
+enum Generic<T: Iterator> {
+    Inner(T),
+    Empty(std::iter::Empty<T::Item>),
+}
+
+#[test]
+fn collect() {
+    let bytes = Bytes::Vec(vec![1, 2, 3].into_iter());
+    assert_eq!((3, Some(3)), bytes.size_hint());
+    assert_eq!(vec![1, 2, 3], bytes.collect::<Vec<_>>());
+    let bytes = Bytes::Array {
+        array: IntoIterator::into_iter([4, 5]),
+    };
+    assert_eq!(vec![4, 5], bytes.collect::<Vec<_>>());
+}
+
+#[test]
+fn generic() {
+    let chars = Generic::Inner("ab".chars());
+    assert_eq!("ab", chars.collect::<String>());
+    let empty = Generic::<std::str::Chars>::Empty(std::iter::empty());
+    assert_eq!(0, empty.count());
+}
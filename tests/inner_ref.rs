@@ -0,0 +1,51 @@
+#![cfg(feature = "inner_ref")]
+
+use std::fmt::Display;
+
+#[impl_enum::inner_ref]
+enum Labeled<T: Display> {
+    Plain(T),
+    Emphasized { value: T, marker: char },
+}
+
+impl<T: Display> Labeled<T> {
+    fn label(&self) -> String {
+        match self {
+            Self::Plain(_) => self.inner().to_string(),
+            Self::Emphasized { marker, .. } => format!("{marker}{}{marker}", self.inner()),
+        }
+    }
+}
+
+#[test]
+fn generic() {
+    let mut plain = Labeled::Plain(1);
+    *plain.inner_mut() += 1;
+    assert_eq!(&2, plain.inner());
+    assert_eq!("2", plain.label());
+    assert_eq!(2, plain.into_inner());
+
+    let mut emphasized = Labeled::Emphasized {
+        value: "text".to_string(),
+        marker: '*',
+    };
+    emphasized.inner_mut().push('!');
+    assert_eq!("*text!*", emphasized.label());
+    assert_eq!("text!", emphasized.into_inner());
+}
+
+#[impl_enum::inner_ref]
+#[derive(Debug)]
+enum Concrete {
+    A(Vec<u8>),
+    B(Vec<u8>),
+}
+
+#[test]
+fn concrete() {
+    let mut a = Concrete::A(vec![]);
+    a.inner_mut().push(1);
+    assert_eq!(&[1], a.inner().as_slice());
+    assert_eq!(vec![1], a.into_inner());
+    assert!(Concrete::B(vec![]).inner().is_empty());
+}
@@ -0,0 +1,31 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![deny(warnings, clippy::all)]
+
+use std::fmt::Debug;
+
+// none of the generated methods are called and they match on a deprecated variant
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn is_empty(&self) -> bool
+    fn clone(&self) -> Self
+}]
+#[impl_enum::with_methods(trait_impls, {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result in std::fmt::Write
+})]
+#[impl_enum::as_dyn(flags(arc, rc), Debug)]
+enum Unused {
+    #[deprecated]
+    String(String),
+    Text {
+        text: String,
+    },
+}
+
+#[test]
+fn unused() {
+    #[allow(deprecated)]
+    let _ = Unused::String(String::new());
+    let _ = Unused::Text {
+        text: String::new(),
+    };
+}
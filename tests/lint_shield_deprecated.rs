@@ -0,0 +1,56 @@
+#![cfg(all(
+    feature = "as_ref",
+    feature = "variant_names",
+    feature = "variant_helpers",
+    feature = "take_or_default",
+    feature = "map_to",
+    feature = "try_parse",
+    feature = "impl_display",
+    feature = "from_variants",
+    feature = "try_into_variants"
+))]
+#![deny(warnings)]
+
+// the generated code uses the deprecated variants without silencing the lint for the other variants
+pub enum Mapped {
+    Old(String),
+    New(String),
+}
+
+#[impl_enum::as_ref(str, as_mut)]
+#[impl_enum::variant_names(from_name)]
+#[impl_enum::variant_helpers]
+#[impl_enum::take_or_default]
+#[impl_enum::map_to(Mapped)]
+#[impl_enum::try_parse]
+#[impl_enum::impl_display]
+enum Same {
+    #[deprecated]
+    Old(String),
+    New(String),
+}
+
+#[impl_enum::from_variants]
+#[impl_enum::try_into_variants]
+enum Different {
+    #[deprecated]
+    Old(String),
+    New(u8),
+}
+
+#[test]
+fn deprecated() {
+    let mut same = Same::New("new".to_string());
+    assert_eq!("new", same.as_ref_str());
+    assert_eq!("new", same.as_mut_str());
+    assert_eq!("New", same.variant_name());
+    assert!(same.is_new());
+    assert_eq!("new", same.to_string());
+    assert!(matches!(same.to_mapped(), Mapped::New(_)));
+    assert!(Same::from_name("New", String::new()).is_some());
+    assert!(Same::try_parse("new").is_some());
+    assert_eq!("", Same::New(String::new()).take_or_default());
+
+    let different = Different::from(1);
+    assert_eq!(Ok(1), u8::try_from(different).map_err(|_| ()));
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "map_inner")]
+#![allow(dead_code)]
+
+use std::{
+    fmt::Write,
+    io::{self, Cursor},
+};
+
+#[impl_enum::map_inner(Write)]
+enum Text {
+    String(String),
+    Lines { lines: Lines, count: usize },
+}
+
+#[derive(Default)]
+struct Lines(Vec<String>);
+
+impl Write for Lines {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.push(s.to_string());
+        Ok(())
+    }
+}
+
+#[impl_enum::map_inner(io::Write + Send)]
+enum Writer {
+    Cursor(Cursor<Vec<u8>>),
+    Vec(Vec<u8>),
+}
+
+#[test]
+fn map_inner() {
+    let mut texts = [
+        Text::String(String::new()),
+        Text::Lines {
+            lines: Lines::default(),
+            count: 0,
+        },
+    ];
+    for text in &mut texts {
+        text.map_inner(|w| w.write_str("a")).unwrap();
+        text.map_inner(|w| write!(w, "{}", 1)).unwrap();
+    }
+    match &texts {
+        [Text::String(string), Text::Lines { lines, .. }] => {
+            assert_eq!("a1", string);
+            assert_eq!(vec!["a", "1"], lines.0);
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn compound_bounds() {
+    let mut writer = Writer::Cursor(Cursor::new(vec![]));
+    let written = writer.map_inner(|w| {
+        fn send(_: &(dyn io::Write + Send)) {}
+        send(w);
+        w.write(b"cursor").unwrap()
+    });
+    assert_eq!(6, written);
+    let mut writer = Writer::Vec(vec![]);
+    writer.map_inner(|w| w.write_all(b"vec")).unwrap();
+    assert!(matches!(writer, Writer::Vec(vec) if vec == b"vec"));
+}
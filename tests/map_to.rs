@@ -0,0 +1,44 @@
+#![cfg(feature = "map_to")]
+#![allow(dead_code)]
+
+mod v2 {
+    #[derive(Debug, PartialEq)]
+    pub enum Request<T> {
+        Get(String),
+        Put { body: T },
+        Delete(String),
+    }
+}
+
+#[impl_enum::map_to(v2::Request<T>)]
+enum Request<T> {
+    Get(String),
+    Put { body: T },
+}
+
+#[test]
+fn map_to() {
+    assert_eq!(
+        v2::Request::Get("path".to_string()),
+        Request::<u8>::Get("path".to_string()).to_request()
+    );
+    assert_eq!(
+        v2::Request::Put { body: 1 },
+        Request::Put { body: 1 }.to_request()
+    );
+}
+
+mod empty {
+    pub enum Never {}
+}
+
+#[impl_enum::map_to(empty::Never)]
+enum Never {}
+
+#[test]
+fn empty_enum() {
+    fn convert(never: Never) -> empty::Never {
+        never.to_never()
+    }
+    let _ = convert;
+}
@@ -0,0 +1,152 @@
+//! The generated code compiles in crates without `std`.
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Write},
+};
+
+#[derive(Debug)]
+struct Lines(Vec<String>);
+
+impl Lines {
+    fn len(&self) -> usize {
+        self.0.iter().map(String::len).sum()
+    }
+}
+
+impl Write for Lines {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.push(s.into());
+        Ok(())
+    }
+}
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn write_str(&mut self, s: &str) -> core::fmt::Result
+}]
+#[impl_enum::as_dyn(flags(alloc, arc, rc, slice), Debug, Write)]
+#[derive(Debug)]
+enum Text {
+    String(String),
+    Lines(Lines),
+}
+
+#[test]
+fn no_std() {
+    let mut text = Text::String(String::new());
+    text.write_str("text").unwrap();
+    assert_eq!(4, text.len());
+    assert_eq!("\"text\"", format!("{:?}", text.into_dyn_debug()));
+
+    let mut text = Text::Lines(Lines(vec![]));
+    text.as_dyn_write_mut().write_str("a").unwrap();
+    assert_eq!(1, text.len());
+    assert_eq!(1, Text::as_dyn_debug_slice(&[text]).len());
+    let arc = Text::String("arc".into()).into_arc_dyn_debug();
+    assert_eq!("\"arc\"", format!("{:?}", arc));
+    let rc = Text::String("rc".into()).into_rc_dyn_debug();
+    assert_eq!("\"rc\"", format!("{:?}", rc));
+}
+
+#[derive(Debug)]
+struct Empty;
+
+impl Display for Empty {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("empty")
+    }
+}
+
+impl Error for Empty {}
+
+trait Piece: Sized {
+    type First: Debug + 'static;
+
+    fn first(&self) -> Self::First;
+    fn non_empty(&self) -> Result<(), Empty>;
+    fn split_words(self) -> Vec<Self>;
+    fn into_count(self: Box<Self>) -> usize;
+}
+
+impl Piece for String {
+    type First = Option<char>;
+
+    fn first(&self) -> Option<char> {
+        self.chars().next()
+    }
+
+    fn non_empty(&self) -> Result<(), Empty> {
+        if self.is_empty() {
+            Err(Empty)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn split_words(self) -> Vec<Self> {
+        self.split(' ').map(String::from).collect()
+    }
+
+    fn into_count(self: Box<Self>) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Piece for Lines {
+    type First = Option<String>;
+
+    fn first(&self) -> Option<String> {
+        self.0.first().cloned()
+    }
+
+    fn non_empty(&self) -> Result<(), Empty> {
+        if self.0.is_empty() {
+            Err(Empty)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn split_words(self) -> Vec<Self> {
+        self.0.into_iter().map(|line| Lines(vec![line])).collect()
+    }
+
+    fn into_count(self: Box<Self>) -> usize {
+        self.0.len()
+    }
+}
+
+#[impl_enum::with_methods(alloc, {
+    #[impl_enum(wrap)]
+    fn first(&self) -> Box<dyn Debug>
+    #[impl_enum(box_error)]
+    fn non_empty(&self) -> Result<(), Box<dyn Error>>
+    fn split_words(self) -> Vec<Self>
+    // the signature is required by the delegated method
+    #[allow(clippy::boxed_local)]
+    fn into_count(self: Box<Self>) -> usize
+})]
+#[derive(Debug)]
+enum Block {
+    String(String),
+    Lines(Lines),
+}
+
+#[test]
+fn allocating() {
+    let string = Block::String("a b".into());
+    assert_eq!("Some('a')", format!("{:?}", string.first()));
+    assert!(string.non_empty().is_ok());
+    assert_eq!(2, string.split_words().len());
+
+    let lines = Block::Lines(Lines(vec![]));
+    assert_eq!("None", format!("{:?}", lines.first()));
+    assert_eq!("empty", format!("{}", lines.non_empty().unwrap_err()));
+    assert_eq!(0, Box::new(lines).into_count());
+}
@@ -0,0 +1,51 @@
+//! `#[non_exhaustive]` is kept on the re-emitted enum, which only affects matches in other crates,
+//! so the generated code that matches exhaustively in this crate doesn't need a wildcard arm.
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![deny(warnings)]
+
+use std::fmt::{Debug, Write};
+
+pub mod value {
+    use std::fmt::Debug;
+
+    #[impl_enum::with_methods {
+        pub fn len(&self) -> usize
+        pub fn is_empty(&self) -> bool
+    }]
+    #[impl_enum::with_methods(trait_impls, {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result in std::fmt::Write
+    })]
+    #[impl_enum::as_dyn(pub; Debug)]
+    #[non_exhaustive]
+    #[derive(Debug)]
+    pub enum Value {
+        String(String),
+        Text {
+            text: String,
+        },
+        #[non_exhaustive]
+        Tagged(String, u8),
+    }
+}
+
+use value::Value;
+
+#[test]
+fn non_exhaustive() {
+    let mut value = Value::String(String::new());
+    value.write_str("string").unwrap();
+    assert_eq!(6, value.len());
+    assert!(!value.is_empty());
+    assert_eq!("\"string\"", format!("{:?}", value.as_dyn_debug()));
+
+    let mut value = Value::Text {
+        text: String::new(),
+    };
+    write!(value, "{}", 1).unwrap();
+    assert_eq!(1, value.len());
+
+    let value = Value::Tagged("tagged".to_string(), 0);
+    assert_eq!(6, value.len());
+    let debug: &dyn Debug = value.as_dyn_debug();
+    assert_eq!("\"tagged\"", format!("{:?}", debug));
+}
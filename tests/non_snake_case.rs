@@ -0,0 +1,77 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![deny(non_snake_case)]
+
+trait HTTPClient {
+    #[allow(non_snake_case)]
+    fn GetURL(&self) -> String;
+    fn get(&self, path: &str) -> String;
+}
+
+struct Local;
+struct Remote {
+    host: &'static str,
+}
+
+impl HTTPClient for Local {
+    fn GetURL(&self) -> String {
+        "localhost".to_string()
+    }
+    fn get(&self, path: &str) -> String {
+        format!("local {path}")
+    }
+}
+
+impl HTTPClient for Remote {
+    fn GetURL(&self) -> String {
+        self.host.to_string()
+    }
+    fn get(&self, path: &str) -> String {
+        format!("remote {path}")
+    }
+}
+
+// the generated method with a name that isn't snake case doesn't trip the lint
+#[impl_enum::with_methods {
+    fn GetURL(&self) -> String in HTTPClient
+    fn get(&self, path: &str) -> String in HTTPClient
+}]
+#[impl_enum::as_dyn(HTTPClient)]
+enum Client {
+    Local(Local),
+    Remote { remote: Remote },
+}
+
+#[test]
+fn non_snake_case() {
+    let local = Client::Local(Local);
+    assert_eq!("localhost", local.GetURL());
+    assert_eq!("local /", local.get("/"));
+    let remote = Client::Remote {
+        remote: Remote {
+            host: "example.com",
+        },
+    };
+    assert_eq!("example.com", remote.as_dyn_http_client().GetURL());
+    assert_eq!("remote /", remote.as_dyn_http_client().get("/"));
+}
+
+// an alias that isn't snake case is converted like the names of the traits
+#[impl_enum::as_dyn(HTTPClient as HTTPClient, std::fmt::Debug as Debug)]
+enum Aliased {
+    Local(Local),
+}
+
+impl std::fmt::Debug for Local {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Local")
+    }
+}
+
+#[test]
+fn alias() {
+    let mut local = Aliased::Local(Local);
+    assert_eq!("localhost", local.as_http_client().GetURL());
+    assert_eq!("local /", local.as_http_client_mut().get("/"));
+    assert_eq!("Local", format!("{:?}", local.as_debug()));
+    assert_eq!("Local", format!("{:?}", local.into_debug()));
+}
@@ -0,0 +1,65 @@
+#![cfg(all(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "variant_names",
+    feature = "variant_helpers"
+))]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+struct Token {
+    r#type: &'static str,
+}
+
+impl Token {
+    fn r#type(&self) -> &'static str {
+        self.r#type
+    }
+}
+
+macro_rules! record {
+    ($name:expr) => {
+        assert_eq!("type", $name)
+    };
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(hook = record)]
+    fn r#type(&self) -> &'static str
+}]
+#[impl_enum::as_dyn(Debug as r#dyn)]
+#[impl_enum::variant_names]
+#[impl_enum::variant_helpers]
+#[derive(Debug)]
+enum Value {
+    r#Box(Token),
+    Field {
+        #[impl_enum(delegate)]
+        r#type: Token,
+        r#ref: u8,
+    },
+}
+
+impl Debug for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.r#type)
+    }
+}
+
+#[test]
+fn raw_identifiers() {
+    let boxed = Value::r#Box(Token { r#type: "boxed" });
+    assert_eq!("boxed", boxed.r#type());
+    assert_eq!("Box", boxed.variant_name());
+    assert!(boxed.is_box());
+    assert_eq!("boxed", boxed.as_box().unwrap().r#type);
+
+    let field = Value::Field {
+        r#type: Token { r#type: "field" },
+        r#ref: 0,
+    };
+    assert_eq!("field", field.r#type());
+    assert_eq!("field", format!("{:?}", field.as_dyn()));
+    assert_eq!(&["Box", "Field"], Value::VARIANT_NAMES);
+}
@@ -0,0 +1,80 @@
+#![cfg(all(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "impl_io_write"
+))]
+
+use std::{fmt::Debug, io::Write};
+
+trait Doubled {
+    fn doubled(self) -> Self;
+}
+
+impl Doubled for Vec<u8> {
+    fn doubled(self) -> Self {
+        self.iter().chain(&self).copied().collect()
+    }
+}
+
+// single variant enums are destructured without a match
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn push(&mut self, value: u8)
+    fn doubled(self) -> Self in Doubled
+    #[impl_enum(tagged)]
+    fn is_empty(&self) -> bool
+}]
+#[impl_enum::as_dyn(Debug)]
+#[impl_enum::impl_io_write]
+#[derive(Debug, PartialEq)]
+enum Single {
+    Only { bytes: Vec<u8>, count: usize },
+}
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+enum Tuple {
+    Only(Vec<u8>),
+}
+
+#[test]
+fn with_methods() {
+    let mut single = Single::Only {
+        bytes: vec![],
+        count: 1,
+    };
+    assert_eq!((0, true), single.is_empty());
+    single.push(1);
+    assert_eq!(1, single.len());
+    assert_eq!(
+        Single::Only {
+            bytes: vec![1, 1],
+            count: 1
+        },
+        single.doubled()
+    );
+    assert_eq!(2, Tuple::Only(vec![1, 2]).len());
+}
+
+#[test]
+fn as_dyn() {
+    let mut single = Single::Only {
+        bytes: vec![1],
+        count: 0,
+    };
+    assert_eq!("[1]", format!("{:?}", single.as_dyn_debug()));
+    assert_eq!("[1]", format!("{:?}", single.as_dyn_debug_mut()));
+    assert_eq!("[1]", format!("{:?}", single.into_dyn_debug()));
+}
+
+#[test]
+fn impl_io_write() {
+    let mut single = Single::Only {
+        bytes: vec![],
+        count: 0,
+    };
+    write!(single, "{}", 12).unwrap();
+    single.flush().unwrap();
+    assert_eq!(2, single.len());
+}
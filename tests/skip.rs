@@ -0,0 +1,74 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![allow(dead_code)]
+
+use std::{
+    fmt::Debug,
+    io::{self, Write},
+};
+
+#[impl_enum::as_dyn(Debug)]
+#[impl_enum::with_methods {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+}]
+enum Writer {
+    Vec(Vec<u8>),
+    Cursor(io::Cursor<Vec<u8>>),
+    #[impl_enum(skip)]
+    Closed,
+    #[impl_enum(skip)]
+    Unsupported(std::rc::Rc<()>),
+}
+
+#[test]
+fn skip() {
+    let mut vec = Writer::Vec(vec![]);
+    assert_eq!(3, vec.write(b"vec").unwrap());
+    assert_eq!("[118, 101, 99]", format!("{:?}", vec.as_dyn_debug()));
+    let mut cursor = Writer::Cursor(io::Cursor::new(vec![]));
+    assert_eq!(6, cursor.write(b"cursor").unwrap());
+}
+
+#[test]
+#[should_panic(expected = "`write` was called on the skipped variant `Closed`")]
+fn skipped_with_methods() {
+    let _ = Writer::Closed.write(b"closed");
+}
+
+#[test]
+#[should_panic(expected = "`as_dyn_debug` was called on the skipped variant `Unsupported`")]
+fn skipped_as_dyn() {
+    let _ = Writer::Unsupported(std::rc::Rc::new(())).as_dyn_debug();
+}
+
+trait Shape {
+    fn sides(&self) -> u32;
+}
+
+struct Square;
+
+impl Shape for Square {
+    fn sides(&self) -> u32 {
+        4
+    }
+}
+
+struct Circle;
+
+#[impl_enum::as_dyn(Shape)]
+enum Figure {
+    Square(Square),
+    #[impl_enum(skip)]
+    Circle(Circle),
+    #[impl_enum(skip)]
+    Empty,
+}
+
+#[test]
+fn try_into_dyn() {
+    let square = Figure::Square(Square).try_into_dyn_shape();
+    assert_eq!(4, square.ok().unwrap().sides());
+    let circle = Figure::Circle(Circle).try_into_dyn_shape();
+    assert!(matches!(circle, Err(Figure::Circle(_))));
+    let empty = Figure::Empty.try_into_dyn_shape();
+    assert!(matches!(empty, Err(Figure::Empty)));
+}
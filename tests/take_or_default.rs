@@ -0,0 +1,38 @@
+#![cfg(feature = "take_or_default")]
+#![allow(dead_code)]
+
+#[impl_enum::take_or_default]
+enum Slot {
+    Empty,
+    Filled(String),
+    Tagged { value: String, tag: u8 },
+}
+
+#[impl_enum::take_or_default]
+enum Generic<T: Default> {
+    None,
+    Some(T),
+}
+
+#[test]
+fn take_or_default() {
+    assert_eq!("", Slot::Empty.take_or_default());
+    assert_eq!(
+        "filled",
+        Slot::Filled("filled".to_string()).take_or_default()
+    );
+    assert_eq!(
+        "tagged",
+        Slot::Tagged {
+            value: "tagged".to_string(),
+            tag: 0
+        }
+        .take_or_default()
+    );
+}
+
+#[test]
+fn generic() {
+    assert_eq!(0, Generic::<u8>::None.take_or_default());
+    assert_eq!(1, Generic::Some(1).take_or_default());
+}
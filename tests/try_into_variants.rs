@@ -0,0 +1,62 @@
+#![cfg(feature = "try_into_variants")]
+#![allow(dead_code)]
+
+use std::{
+    convert::{TryFrom, TryInto},
+    io::{Cursor, Write},
+};
+
+#[impl_enum::try_into_variants]
+#[derive(Debug)]
+enum Writer {
+    Cursor(Cursor<Vec<u8>>),
+    Vec {
+        vec: Vec<u8>,
+    },
+    // the field type is the same as that of `Cursor`
+    #[impl_enum(skip)]
+    Other(Cursor<Vec<u8>>),
+}
+
+#[impl_enum::try_into_variants]
+#[derive(Debug)]
+enum Single {
+    Value(String),
+}
+
+#[impl_enum::try_into_variants]
+#[derive(Debug)]
+enum Generic<T> {
+    List(Vec<T>),
+    #[impl_enum(skip)]
+    Value(T),
+}
+
+#[test]
+fn try_into_variants() {
+    let mut cursor: Cursor<Vec<u8>> = Writer::Cursor(Cursor::new(vec![])).try_into().unwrap();
+    cursor.write_all(b"cursor").unwrap();
+    assert_eq!(b"cursor", cursor.get_ref().as_slice());
+
+    let vec = Vec::<u8>::try_from(Writer::Vec { vec: vec![1] }).unwrap();
+    assert_eq!(vec![1], vec);
+
+    let single = String::try_from(Single::Value("single".to_string())).unwrap();
+    assert_eq!("single", single);
+
+    let list = Vec::<i32>::try_from(Generic::List(vec![1])).unwrap();
+    assert_eq!(vec![1], list);
+}
+
+#[test]
+fn mismatch() {
+    let writer = Vec::<u8>::try_from(Writer::Cursor(Cursor::new(vec![1]))).unwrap_err();
+    assert!(matches!(writer, Writer::Cursor(_)));
+
+    // skipped variants are returned as well
+    let writer = Cursor::<Vec<u8>>::try_from(Writer::Other(Cursor::new(vec![]))).unwrap_err();
+    assert!(matches!(writer, Writer::Other(_)));
+
+    let generic = Vec::<i32>::try_from(Generic::Value(1)).unwrap_err();
+    assert!(matches!(generic, Generic::Value(1)));
+}
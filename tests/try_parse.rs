@@ -0,0 +1,41 @@
+#![cfg(feature = "try_parse")]
+
+use std::net::{IpAddr, SocketAddr};
+
+#[impl_enum::try_parse]
+#[derive(Debug, PartialEq)]
+enum Address {
+    Socket(SocketAddr),
+    Ip { ip: IpAddr },
+    Port(u16),
+}
+
+#[test]
+fn try_parse() {
+    assert_eq!(
+        Some(Address::Socket("127.0.0.1:80".parse().unwrap())),
+        Address::try_parse("127.0.0.1:80")
+    );
+    assert_eq!(
+        Some(Address::Ip {
+            ip: "::1".parse().unwrap()
+        }),
+        Address::try_parse("::1")
+    );
+    assert_eq!(Some(Address::Port(8080)), Address::try_parse("8080"));
+    assert_eq!(None, Address::try_parse("localhost"));
+}
+
+// the first variant that can parse the string is used
+#[impl_enum::try_parse]
+#[derive(Debug, PartialEq)]
+enum Number {
+    Small(u8),
+    Large(u64),
+}
+
+#[test]
+fn ordering() {
+    assert_eq!(Some(Number::Small(1)), Number::try_parse("1"));
+    assert_eq!(Some(Number::Large(256)), Number::try_parse("256"));
+}
@@ -0,0 +1,39 @@
+//! Variant attributes survive the re-emitted enum.
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+#![deny(missing_docs)]
+
+use std::fmt::Debug;
+
+/// A value with documented variants.
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+#[derive(Debug, PartialEq)]
+pub enum Documented {
+    /// Bytes.
+    #[cfg_attr(all(), allow(unused))]
+    Bytes(Vec<u8>),
+    /// Text.
+    #[allow(clippy::all)]
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// Deprecated.
+    #[deprecated = "use Text instead"]
+    Old(String),
+}
+
+#[test]
+fn variant_attrs() {
+    assert_eq!(0, Documented::Bytes(vec![]).len());
+    let text = Documented::Text {
+        text: "abc".to_string(),
+    };
+    assert_eq!(3, text.len());
+    #[allow(deprecated)]
+    let old = Documented::Old("ab".to_string());
+    assert_eq!(2, old.len());
+    assert_eq!("\"ab\"", format!("{:?}", old.as_dyn_debug()));
+}
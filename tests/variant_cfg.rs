@@ -0,0 +1,53 @@
+#![cfg(all(
+    feature = "with_methods",
+    feature = "as_dyn",
+    feature = "variant_names",
+    feature = "from_variants"
+))]
+
+use std::fmt::Debug;
+
+// the gated variants refer to a type that doesn't exist,
+// so the code generated for them only compiles if it's removed along with the variant
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[impl_enum::as_dyn(Debug)]
+#[impl_enum::variant_names]
+#[impl_enum::from_variants]
+#[derive(Debug)]
+enum Value {
+    String(String),
+    #[cfg(any())]
+    Missing(Missing),
+    #[cfg(test)]
+    Test {
+        test: Vec<u8>,
+    },
+}
+
+// a single remaining variant is matched with a gated arm
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+enum Single {
+    String(String),
+    #[cfg(any())]
+    Missing(Missing),
+}
+
+#[test]
+fn variant_cfg() {
+    let value = Value::from("string".to_string());
+    assert_eq!(6, value.len());
+    assert_eq!("\"string\"", format!("{:?}", value.as_dyn_debug()));
+    assert_eq!("String", value.variant_name());
+
+    let value = Value::from(vec![1]);
+    assert_eq!(1, value.len());
+    assert_eq!("Test", value.variant_name());
+    assert_eq!(&["String", "Test"], Value::VARIANT_NAMES);
+    assert_eq!(&["String", "Test"], Value::VARIANTS);
+
+    assert_eq!(1, Single::String("a".to_string()).len());
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "variant_helpers")]
+#![allow(dead_code)]
+
+use std::{fs::File, io::Cursor};
+
+#[impl_enum::variant_helpers]
+enum Writer<'a> {
+    Cursor(Cursor<Vec<u8>>),
+    File { file: File },
+    Sink,
+    BorrowedBuffer(&'a mut [u8]),
+}
+
+#[test]
+fn predicates() {
+    let cursor = Writer::Cursor(Cursor::new(vec![]));
+    assert!(cursor.is_cursor());
+    assert!(!cursor.is_file());
+    assert!(!cursor.is_sink());
+
+    let sink = Writer::Sink;
+    assert!(sink.is_sink());
+    assert!(!sink.is_cursor());
+
+    let mut buffer = [0; 2];
+    let borrowed = Writer::BorrowedBuffer(&mut buffer);
+    assert!(borrowed.is_borrowed_buffer());
+    assert!(!borrowed.is_file());
+}
+
+#[impl_enum::variant_helpers]
+enum Value {
+    Integer(i64),
+    Pair(i64, String),
+    Labeled {
+        #[impl_enum(delegate)]
+        value: i64,
+        label: String,
+    },
+}
+
+#[impl_enum::variant_helpers]
+enum Single {
+    Text { text: String },
+}
+
+#[test]
+fn accessors() {
+    let cursor = Writer::Cursor(Cursor::new(vec![1]));
+    assert_eq!(&[1], cursor.as_cursor().unwrap().get_ref().as_slice());
+    assert!(cursor.as_file().is_none());
+    assert!(cursor.as_borrowed_buffer().is_none());
+
+    let integer = Value::Integer(1);
+    assert_eq!(Some(&1), integer.as_integer());
+    assert_eq!(None, integer.as_pair());
+    assert_eq!(None, integer.as_labeled());
+
+    let pair = Value::Pair(2, "pair".to_string());
+    assert_eq!(Some((&2, &"pair".to_string())), pair.as_pair());
+    assert_eq!(None, pair.as_integer());
+
+    let labeled = Value::Labeled {
+        value: 3,
+        label: "label".to_string(),
+    };
+    assert_eq!(Some(&3), labeled.as_labeled());
+
+    let single = Single::Text {
+        text: "text".to_string(),
+    };
+    assert_eq!("text", single.as_text().unwrap());
+}
+
+#[test]
+fn into_accessors() {
+    let cursor = Writer::Cursor(Cursor::new(vec![1]));
+    assert_eq!(vec![1], cursor.into_cursor().unwrap().into_inner());
+    // the value is dropped when it's of another variant
+    assert!(Writer::Sink.into_cursor().is_none());
+
+    assert_eq!(
+        Some((2, "pair".to_string())),
+        Value::Pair(2, "pair".to_string()).into_pair()
+    );
+    assert_eq!(None, Value::Integer(1).into_pair());
+    let labeled = Value::Labeled {
+        value: 3,
+        label: "label".to_string(),
+    };
+    assert_eq!(Some(3), labeled.into_labeled());
+
+    let single = Single::Text {
+        text: "text".to_string(),
+    };
+    assert_eq!("text", single.into_text().unwrap());
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "variant_names")]
+#![allow(dead_code)]
+
+#[impl_enum::variant_names(from_name)]
+#[derive(Debug, PartialEq)]
+enum Config {
+    Verbose(u32),
+    Quiet { level: u32 },
+}
+
+#[impl_enum::variant_names]
+enum Mixed {
+    Unit,
+    Tuple(u8, u16),
+    Named { s: String },
+}
+
+#[test]
+fn from_name() {
+    assert_eq!(&["Verbose", "Quiet"], Config::VARIANT_NAMES);
+    for value in [Config::Verbose(1), Config::Quiet { level: 2 }] {
+        let name = value.variant_name();
+        let inner = match value {
+            Config::Verbose(inner) | Config::Quiet { level: inner } => inner,
+        };
+        assert_eq!(Some(value), Config::from_name(name, inner));
+    }
+    assert_eq!(None, Config::from_name("verbose", 1));
+}
+
+#[test]
+fn mixed() {
+    assert_eq!(&["Unit", "Tuple", "Named"], Mixed::VARIANT_NAMES);
+    assert_eq!(&["Unit", "Tuple", "Named"], Mixed::VARIANTS);
+    assert_eq!("Unit", Mixed::Unit.variant_name());
+    assert_eq!("Tuple", Mixed::Tuple(0, 0).variant_name());
+    assert_eq!("Named", Mixed::Named { s: String::new() }.variant_name());
+}
+
+#[impl_enum::variant_names]
+enum Never {}
+
+#[test]
+fn empty_enum() {
+    fn assert_methods(never: Option<Never>) {
+        if let Some(never) = never {
+            let _ = never.variant_name();
+        }
+    }
+    assert_methods(None);
+    assert!(Never::VARIANT_NAMES.is_empty());
+}
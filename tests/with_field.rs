@@ -0,0 +1,44 @@
+#![cfg(feature = "with_field")]
+
+#[impl_enum::with_field]
+#[derive(Debug, PartialEq)]
+enum Buffer<'a> {
+    Owned(Vec<u8>),
+    Shared { buf: Vec<u8>, name: &'a str },
+}
+
+#[test]
+fn with_field() {
+    let owned = Buffer::Owned(vec![1, 2]);
+    assert_eq!(2, owned.with_field(|buf| buf.len()));
+
+    let shared = Buffer::Shared {
+        buf: vec![1],
+        name: "shared",
+    };
+    assert_eq!(
+        Some(&1),
+        shared.with_field(|buf| buf.first().copied()).as_ref()
+    );
+}
+
+#[test]
+fn with_field_mut() {
+    let mut owned = Buffer::Owned(vec![]);
+    owned.with_field_mut(|buf| buf.push(1));
+    assert_eq!(Buffer::Owned(vec![1]), owned);
+
+    let mut shared = Buffer::Shared {
+        buf: vec![1, 2],
+        name: "shared",
+    };
+    let popped = shared.with_field_mut(|buf| buf.pop());
+    assert_eq!(Some(2), popped);
+    assert_eq!(
+        Buffer::Shared {
+            buf: vec![1],
+            name: "shared"
+        },
+        shared
+    );
+}
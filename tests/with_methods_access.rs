@@ -0,0 +1,88 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+// the `W: Write` bound on the enum is used by the generated delegation
+#[impl_enum::with_methods {
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()>
+    fn flush(&mut self) -> io::Result<()>
+}]
+enum Dispatch<W: Write> {
+    Owned(W),
+    #[impl_enum(access = |shared| shared.lock().unwrap())]
+    Shared(Arc<Mutex<W>>),
+}
+
+#[test]
+fn access() {
+    let mut owned = Dispatch::Owned(vec![]);
+    owned.write_all(b"owned").unwrap();
+    owned.flush().unwrap();
+    match owned {
+        Dispatch::Owned(vec) => assert_eq!(b"owned", vec.as_slice()),
+        Dispatch::Shared(_) => panic!(),
+    }
+
+    let inner = Arc::new(Mutex::new(vec![]));
+    let mut shared = Dispatch::Shared(Arc::clone(&inner));
+    shared.write_all(b"shared").unwrap();
+    shared.flush().unwrap();
+    assert_eq!(b"shared", inner.lock().unwrap().as_slice());
+}
+
+// the helper attributes are kept until the last macro from the crate is expanded
+#[impl_enum::as_dyn(std::fmt::Debug)]
+#[impl_enum::with_methods {
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()>
+}]
+enum Combined {
+    Owned(Vec<u8>),
+    #[impl_enum(access = |shared| shared.lock().unwrap())]
+    Shared(Arc<Mutex<Vec<u8>>>),
+}
+
+#[test]
+fn combined() {
+    let inner = Arc::new(Mutex::new(vec![]));
+    let mut shared = Combined::Shared(Arc::clone(&inner));
+    shared.write_all(b"shared").unwrap();
+    assert_eq!(b"shared", inner.lock().unwrap().as_slice());
+    assert!(format!("{:?}", shared.as_dyn_debug()).starts_with("Mutex"));
+    assert!(matches!(Combined::Owned(vec![]), Combined::Owned(_)));
+}
+
+struct Concrete(Vec<u8>);
+
+impl Concrete {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+// method call syntax dereferences the field through any number of smart pointers
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[allow(clippy::redundant_allocation)]
+enum Wrapped {
+    Direct(Concrete),
+    #[impl_enum(access = |field| field)]
+    Boxed(Box<Concrete>),
+    #[impl_enum(access = |field| field)]
+    Shared {
+        shared: Arc<Box<Concrete>>,
+    },
+}
+
+#[test]
+fn deref_chain() {
+    assert_eq!(1, Wrapped::Direct(Concrete(vec![1])).len());
+    assert_eq!(2, Wrapped::Boxed(Box::new(Concrete(vec![1, 2]))).len());
+    let shared = Wrapped::Shared {
+        shared: Arc::new(Box::new(Concrete(vec![1, 2, 3]))),
+    };
+    assert_eq!(3, shared.len());
+}
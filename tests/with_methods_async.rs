@@ -0,0 +1,122 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+};
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(Noop).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+struct Chunks(Vec<Vec<u8>>);
+struct Whole(Vec<u8>);
+
+impl Chunks {
+    async fn each<F, Fut>(&self, f: F) -> usize
+    where
+        F: Fn(&[u8]) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        for chunk in &self.0 {
+            f(chunk).await;
+        }
+        self.0.len()
+    }
+}
+
+impl Whole {
+    async fn each<F, Fut>(&self, f: F) -> usize
+    where
+        F: Fn(&[u8]) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        f(&self.0).await;
+        1
+    }
+}
+
+#[impl_enum::with_methods {
+    async fn each<F, Fut>(&self, f: F) -> usize where F: Fn(&[u8]) -> Fut, Fut: Future<Output = ()>
+}]
+enum Body {
+    Chunks(Chunks),
+    Whole { whole: Whole },
+}
+
+#[test]
+fn async_callback() {
+    let received = RefCell::new(vec![]);
+    let callback = |bytes: &[u8]| {
+        let bytes = bytes.to_vec();
+        let received = &received;
+        async move {
+            received.borrow_mut().push(bytes);
+        }
+    };
+
+    let chunks = Body::Chunks(Chunks(vec![vec![1], vec![2, 3]]));
+    assert_eq!(2, block_on(chunks.each(callback)));
+    let whole = Body::Whole {
+        whole: Whole(vec![4, 5]),
+    };
+    assert_eq!(1, block_on(whole.each(callback)));
+
+    assert_eq!(vec![vec![1], vec![2, 3], vec![4, 5]], received.into_inner());
+}
+
+trait Connection {
+    async fn send(&mut self, msg: &str) -> Result<usize, String>;
+}
+
+struct Tcp(Vec<String>);
+struct Closed;
+
+impl Connection for Tcp {
+    async fn send(&mut self, msg: &str) -> Result<usize, String> {
+        self.0.push(msg.to_string());
+        Ok(msg.len())
+    }
+}
+
+impl Connection for Closed {
+    async fn send(&mut self, _msg: &str) -> Result<usize, String> {
+        Err("closed".to_string())
+    }
+}
+
+#[impl_enum::with_methods {
+    async fn send(&mut self, msg: &str) -> Result<usize, String> in Connection
+}]
+enum AnyConnection {
+    Tcp(Tcp),
+    Closed(Closed),
+}
+
+#[test]
+fn async_trait() {
+    let mut tcp = AnyConnection::Tcp(Tcp(vec![]));
+    assert_eq!(Ok(5), block_on(tcp.send("hello")));
+    match tcp {
+        AnyConnection::Tcp(tcp) => assert_eq!(vec!["hello".to_string()], tcp.0),
+        AnyConnection::Closed(_) => panic!(),
+    }
+    let mut closed = AnyConnection::Closed(Closed);
+    assert_eq!(Err("closed".to_string()), block_on(closed.send("hello")));
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "with_methods")]
+#![deny(unused_must_use)]
+
+#[impl_enum::with_methods {
+    /// Returns the length
+    /// of the collection.
+    #[inline]
+    fn len(&self) -> usize
+    #[must_use]
+    #[impl_enum(tagged)]
+    fn is_empty(&self) -> bool
+    #[cold]
+    #[allow(clippy::len_zero)]
+    pub fn capacity(&self) -> usize
+}]
+enum Collection {
+    Vec(Vec<u8>),
+    String(String),
+}
+
+#[test]
+fn attrs() {
+    let vec = Collection::Vec(vec![1, 2]);
+    assert_eq!(2, vec.len());
+    assert_eq!((0, false), vec.is_empty());
+    let string = Collection::String(String::with_capacity(4));
+    assert_eq!((1, true), string.is_empty());
+    assert!(string.capacity() >= 4);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "with_methods")]
+
+use std::{error::Error, fmt, num::ParseIntError};
+
+#[derive(Debug)]
+struct CustomError;
+
+impl fmt::Display for CustomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "custom error")
+    }
+}
+
+impl Error for CustomError {}
+
+struct Parser(&'static str);
+struct Custom(bool);
+struct Boxed(Option<u32>);
+
+impl Parser {
+    fn run(&self) -> Result<u32, ParseIntError> {
+        self.0.parse()
+    }
+}
+
+impl Custom {
+    fn run(&self) -> Result<u32, CustomError> {
+        if self.0 {
+            Ok(1)
+        } else {
+            Err(CustomError)
+        }
+    }
+}
+
+impl Boxed {
+    fn run(&self) -> Result<u32, Box<dyn Error>> {
+        self.0
+            .ok_or_else(|| Box::new(CustomError) as Box<dyn Error>)
+    }
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(box_error)]
+    fn run(&self) -> Result<u32, Box<dyn Error>>
+}]
+enum Task {
+    Parser(Parser),
+    Custom { custom: Custom },
+    Boxed(Boxed),
+}
+
+#[test]
+fn box_error() {
+    assert_eq!(12, Task::Parser(Parser("12")).run().unwrap());
+    let err = Task::Parser(Parser("a")).run().unwrap_err();
+    assert!(err.downcast_ref::<ParseIntError>().is_some());
+
+    assert_eq!(
+        1,
+        Task::Custom {
+            custom: Custom(true)
+        }
+        .run()
+        .unwrap()
+    );
+    let err = Task::Custom {
+        custom: Custom(false),
+    }
+    .run()
+    .unwrap_err();
+    assert_eq!("custom error", err.to_string());
+
+    // already boxed errors are not boxed again
+    assert_eq!(2, Task::Boxed(Boxed(Some(2))).run().unwrap());
+    let err = Task::Boxed(Boxed(None)).run().unwrap_err();
+    assert!(err.downcast_ref::<CustomError>().is_some());
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "with_methods")]
+
+trait Consume {
+    fn consume(self: Box<Self>, suffix: &str) -> String;
+}
+
+struct Name(String);
+struct Number(u32);
+
+impl Consume for Name {
+    fn consume(self: Box<Self>, suffix: &str) -> String {
+        self.0 + suffix
+    }
+}
+
+impl Consume for Number {
+    fn consume(self: Box<Self>, suffix: &str) -> String {
+        self.0.to_string() + suffix
+    }
+}
+
+#[impl_enum::with_methods(trait_impls, {
+    fn consume(self: Box<Self>, suffix: &str) -> String in Consume
+})]
+enum Value {
+    Name(Name),
+    Number { number: Number },
+}
+
+#[test]
+fn box_self() {
+    let name = Box::new(Value::Name(Name("name".to_string())));
+    assert_eq!("name!", name.consume("!"));
+    let number: Box<dyn Consume> = Box::new(Value::Number { number: Number(1) });
+    assert_eq!("1?", number.consume("?"));
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "with_methods")]
+
+trait Named {
+    fn name(&self) -> String;
+}
+
+trait Sized2D {
+    fn width(&self) -> u32;
+}
+
+struct Square(u32);
+struct Circle;
+
+impl Named for Square {
+    fn name(&self) -> String {
+        "square".to_string()
+    }
+}
+impl Sized2D for Square {
+    fn width(&self) -> u32 {
+        self.0
+    }
+}
+impl Named for Circle {
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+}
+impl Sized2D for Circle {
+    fn width(&self) -> u32 {
+        2
+    }
+}
+
+#[impl_enum::with_methods(check, {
+    fn name(&self) -> String in Named
+    fn width(&self) -> u32 in Sized2D
+})]
+enum Shape {
+    Square(Square),
+    Circle { circle: Circle },
+}
+
+// the checks of separate invocations don't clash
+#[impl_enum::with_methods(check, {
+    fn name(&self) -> String in Named
+})]
+#[impl_enum::with_methods(check, {
+    fn width(&self) -> u32 in Sized2D
+})]
+enum Split<T: Named + Sized2D> {
+    Square(Square),
+    Other { other: T },
+}
+
+#[test]
+fn check() {
+    let square = Shape::Square(Square(4));
+    assert_eq!("square", square.name());
+    assert_eq!(4, square.width());
+    let circle = Shape::Circle { circle: Circle };
+    assert_eq!("circle", circle.name());
+    assert_eq!(2, circle.width());
+}
+
+#[test]
+fn separate_invocations() {
+    let square = Split::<Circle>::Square(Square(3));
+    assert_eq!("square", square.name());
+    assert_eq!(3, square.width());
+    let other = Split::Other { other: Circle };
+    assert_eq!("circle", other.name());
+    assert_eq!(2, other.width());
+}
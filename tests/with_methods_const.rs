@@ -0,0 +1,36 @@
+#![cfg(feature = "with_methods")]
+
+struct Small([u8; 4]);
+struct Large([u8; 16]);
+
+impl Small {
+    const fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Large {
+    const fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[impl_enum::with_methods {
+    const fn capacity(&self) -> usize
+}]
+enum Buffer {
+    Small(Small),
+    Large { large: Large },
+}
+
+const SMALL: usize = Buffer::Small(Small([0; 4])).capacity();
+const LARGE: usize = Buffer::Large {
+    large: Large([0; 16]),
+}
+.capacity();
+
+#[test]
+fn const_fn() {
+    assert_eq!(4, SMALL);
+    assert_eq!(16, LARGE);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "with_methods")]
+
+struct Leaf;
+
+struct Branch(Vec<Branch>);
+
+trait Children: Sized {
+    fn children(&self) -> Vec<Self>;
+    fn first_child(&self) -> Option<Self>;
+}
+
+impl Children for Leaf {
+    fn children(&self) -> Vec<Self> {
+        vec![]
+    }
+    fn first_child(&self) -> Option<Self> {
+        None
+    }
+}
+
+impl Children for Branch {
+    fn children(&self) -> Vec<Self> {
+        self.0
+            .iter()
+            .map(|branch| Branch(branch.children()))
+            .collect()
+    }
+    fn first_child(&self) -> Option<Self> {
+        self.0.first().map(|branch| Branch(branch.children()))
+    }
+}
+
+#[impl_enum::with_methods {
+    fn children(&self) -> Vec<Self> in Children
+    fn first_child(&self) -> Option<Self> in Children
+}]
+enum Node {
+    Leaf(Leaf),
+    Branch { branch: Branch },
+}
+
+fn count(node: &Node) -> usize {
+    1 + node.children().iter().map(count).sum::<usize>()
+}
+
+#[test]
+fn tree() {
+    let leaf = Node::Leaf(Leaf);
+    assert!(leaf.children().is_empty());
+    assert!(leaf.first_child().is_none());
+
+    let tree = Node::Branch {
+        branch: Branch(vec![Branch(vec![Branch(vec![])]), Branch(vec![])]),
+    };
+    assert_eq!(2, tree.children().len());
+    assert_eq!(4, count(&tree));
+    let first = tree.first_child().unwrap();
+    assert!(matches!(first, Node::Branch { .. }));
+    assert_eq!(1, first.children().len());
+}
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[derive(Debug, PartialEq)]
+struct Path(String);
+
+impl Port {
+    fn with_offset(self, offset: u16) -> Result<Self, String> {
+        self.0
+            .checked_add(offset)
+            .map(Port)
+            .ok_or_else(|| "overflow".to_string())
+    }
+
+    fn parent(self) -> Option<Self> {
+        None
+    }
+}
+
+impl Path {
+    fn with_offset(self, _offset: u16) -> Result<Self, String> {
+        Err("not a port".to_string())
+    }
+
+    fn parent(self) -> Option<Self> {
+        let (parent, _) = self.0.rsplit_once('/')?;
+        Some(Path(parent.to_string()))
+    }
+}
+
+// the other fields are moved into the new value
+#[impl_enum::with_methods {
+    fn with_offset(self, offset: u16) -> Result<Self, String>
+    fn parent(self) -> Option<Self>
+}]
+#[derive(Debug, PartialEq)]
+enum Address {
+    Port(Port, &'static str),
+    Path { path: Path, host: &'static str },
+}
+
+#[test]
+fn result_and_option() {
+    let port = Address::Port(Port(80), "localhost");
+    assert_eq!(
+        Ok(Address::Port(Port(8080), "localhost")),
+        port.with_offset(8000)
+    );
+    let port = Address::Port(Port(u16::MAX), "localhost");
+    assert_eq!(Err("overflow".to_string()), port.with_offset(1));
+    assert_eq!(None, Address::Port(Port(80), "localhost").parent());
+
+    let path = Address::Path {
+        path: Path("/a/b".to_string()),
+        host: "remote",
+    };
+    assert_eq!(
+        Some(Address::Path {
+            path: Path("/a".to_string()),
+            host: "remote",
+        }),
+        path.parent()
+    );
+    let path = Address::Path {
+        path: Path("a".to_string()),
+        host: "remote",
+    };
+    assert_eq!(Err("not a port".to_string()), path.with_offset(1));
+}
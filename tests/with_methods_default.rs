@@ -0,0 +1,44 @@
+#![cfg(feature = "with_methods")]
+
+use std::io::{self, Write};
+
+#[impl_enum::with_methods {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+}]
+enum Writer {
+    Vec(Vec<u8>),
+    #[impl_enum(default = Ok(buf.len()))]
+    Null,
+}
+
+#[test]
+fn default_expr() {
+    let mut vec = Writer::Vec(vec![]);
+    assert_eq!(3, vec.write(b"vec").unwrap());
+    let mut null = Writer::Null;
+    assert_eq!(4, null.write(b"null").unwrap());
+}
+
+#[impl_enum::with_methods {
+    fn clear(&mut self)
+    fn len(&self) -> usize
+}]
+enum Buffer {
+    Vec(Vec<u8>),
+    String(String),
+    #[impl_enum(default)]
+    Empty,
+}
+
+#[test]
+fn default_value() {
+    let mut vec = Buffer::Vec(vec![1, 2]);
+    assert_eq!(2, vec.len());
+    vec.clear();
+    assert_eq!(0, vec.len());
+    let string = Buffer::String("abc".to_string());
+    assert_eq!(3, string.len());
+    let mut empty = Buffer::Empty;
+    empty.clear();
+    assert_eq!(0, empty.len());
+}
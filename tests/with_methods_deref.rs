@@ -0,0 +1,79 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    io::{self, Write},
+    sync::Arc,
+};
+
+// the methods are found through `Deref` with method call syntax
+#[impl_enum::with_methods {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    fn flush(&mut self) -> io::Result<()>
+}]
+enum Output {
+    Dyn(Box<dyn Write>),
+    Vec(Vec<u8>),
+}
+
+struct Name(String);
+
+impl Name {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn kind() -> &'static str {
+        "name"
+    }
+}
+
+struct Id(u32);
+
+impl Id {
+    fn len(&self) -> usize {
+        self.0.to_string().len()
+    }
+
+    fn kind() -> &'static str {
+        "id"
+    }
+}
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+}]
+#[allow(clippy::redundant_allocation)]
+enum Shared {
+    Name(Arc<Box<Name>>),
+    Id(Id),
+}
+
+// associated functions are called on the field type
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn kind() -> &'static str
+}]
+enum Owned {
+    Name(Name),
+    Id(Id),
+}
+
+#[test]
+fn deref() {
+    let mut output = Output::Dyn(Box::new(io::sink()));
+    assert_eq!(3, output.write(b"dyn").unwrap());
+    output.flush().unwrap();
+    let mut output = Output::Vec(vec![]);
+    assert_eq!(3, output.write(b"vec").unwrap());
+
+    let name = Shared::Name(Arc::new(Box::new(Name("name".to_string()))));
+    assert_eq!(4, name.len());
+    let id = Shared::Id(Id(12));
+    assert_eq!(2, id.len());
+
+    let name = Owned::Name(Name("name".to_string()));
+    assert_eq!("name", name.kind());
+    let id = Owned::Id(Id(12));
+    assert_eq!(2, id.len());
+    assert_eq!("id", id.kind());
+}
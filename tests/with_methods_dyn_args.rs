@@ -0,0 +1,61 @@
+#![cfg(feature = "with_methods")]
+
+use std::io::{self, Write};
+
+trait Source {
+    fn copy_into(&self, dst: &mut dyn Write) -> io::Result<u64>;
+}
+
+struct Bytes(Vec<u8>);
+struct Repeat {
+    byte: u8,
+    count: usize,
+}
+
+impl Source for Bytes {
+    fn copy_into(&self, dst: &mut dyn Write) -> io::Result<u64> {
+        dst.write_all(&self.0)?;
+        Ok(self.0.len() as u64)
+    }
+}
+
+impl Source for Repeat {
+    fn copy_into(&self, dst: &mut dyn Write) -> io::Result<u64> {
+        for _ in 0..self.count {
+            dst.write_all(&[self.byte])?;
+        }
+        Ok(self.count as u64)
+    }
+}
+
+#[impl_enum::with_methods {
+    fn copy_into(&self, dst: &mut dyn Write) -> io::Result<u64>
+}]
+enum AnySource {
+    Bytes(Bytes),
+    Repeat { repeat: Repeat },
+}
+
+#[test]
+fn shared_dyn_write() {
+    let sources = [
+        AnySource::Bytes(Bytes(b"ab".to_vec())),
+        AnySource::Repeat {
+            repeat: Repeat {
+                byte: b'c',
+                count: 3,
+            },
+        },
+    ];
+
+    let mut buf = vec![];
+    let dst: &mut dyn Write = &mut buf;
+    let mut copied = 0;
+    for source in &sources {
+        // the same trait object is reborrowed for every call
+        copied += source.copy_into(dst).unwrap();
+    }
+    dst.flush().unwrap();
+    assert_eq!(5, copied);
+    assert_eq!(b"abccc", buf.as_slice());
+}
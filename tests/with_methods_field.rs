@@ -0,0 +1,122 @@
+#![cfg(feature = "with_methods")]
+#![allow(dead_code)]
+
+trait Reversed {
+    fn reversed(self) -> Self;
+}
+
+impl Reversed for Vec<u8> {
+    fn reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+}
+
+#[impl_enum::with_methods(field = 1, {
+    fn len(&self) -> usize
+    fn push(&mut self, value: u8)
+    fn reversed(self) -> Self in Reversed
+})]
+#[derive(Debug, PartialEq)]
+enum Tagged {
+    First(&'static str, Vec<u8>),
+    Second(u32, Vec<u8>, bool),
+    #[impl_enum(field = data)]
+    Named {
+        tag: u8,
+        data: Vec<u8>,
+    },
+}
+
+#[test]
+fn second_field() {
+    let mut first = Tagged::First("first", vec![1]);
+    first.push(2);
+    assert_eq!(2, first.len());
+    assert_eq!(Tagged::First("first", vec![1, 2]), first);
+
+    let mut second = Tagged::Second(2, vec![], true);
+    second.push(1);
+    assert_eq!(1, second.len());
+    second.push(2);
+    assert_eq!(Tagged::Second(2, vec![2, 1], true), second.reversed());
+}
+
+#[test]
+fn override_field() {
+    let mut named = Tagged::Named {
+        tag: 3,
+        data: vec![],
+    };
+    named.push(1);
+    assert_eq!(1, named.len());
+    assert_eq!(
+        Tagged::Named {
+            tag: 3,
+            data: vec![1]
+        },
+        named
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Primary,
+    Replica,
+}
+
+struct Backend {
+    name: &'static str,
+}
+
+impl Backend {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// the leading tag field is ignored when binding the delegate field
+#[impl_enum::with_methods(field = 1, {
+    fn name(&self) -> &'static str
+})]
+enum KindTagged {
+    A(Kind, Backend),
+    B(Kind, Backend),
+}
+
+impl KindTagged {
+    fn kind(&self) -> Kind {
+        match self {
+            Self::A(kind, _) | Self::B(kind, _) => *kind,
+        }
+    }
+}
+
+#[test]
+fn leading_tag() {
+    let a = KindTagged::A(Kind::Primary, Backend { name: "a" });
+    assert_eq!("a", a.name());
+    assert_eq!(Kind::Primary, a.kind());
+    let b = KindTagged::B(Kind::Replica, Backend { name: "b" });
+    assert_eq!("b", b.name());
+    assert_eq!(Kind::Replica, b.kind());
+}
+
+// an index selects the field by position in named variants too
+#[impl_enum::with_methods(field = 1, {
+    fn len(&self) -> usize
+})]
+enum Positional {
+    Tuple(u8, String),
+    Named { id: u8, name: String },
+}
+
+#[test]
+fn positional() {
+    assert_eq!(3, Positional::Tuple(0, "abc".to_string()).len());
+    let named = Positional::Named {
+        id: 0,
+        name: "ab".to_string(),
+    };
+    assert_eq!(2, named.len());
+}
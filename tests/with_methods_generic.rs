@@ -1,6 +1,7 @@
 #![cfg(feature = "with_methods")]
 
 use impl_enum::with_methods;
+use std::io::Write;
 
 trait A {
     fn f() -> &'static str;
@@ -41,3 +42,35 @@ fn t() {
     let generic = Generic::<(), ()>::U(());
     assert_eq!("B", generic.f());
 }
+
+struct Stub;
+
+impl Write for Stub {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[with_methods {
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()>
+}]
+enum Bounded<T: Write> {
+    A(T),
+    B(Stub),
+}
+
+#[test]
+fn bounded_type_parameter() {
+    let mut a = Bounded::A(vec![]);
+    a.write_all(b"hello").unwrap();
+    match a {
+        Bounded::A(vec) => assert_eq!(b"hello", vec.as_slice()),
+        Bounded::B(_) => panic!(),
+    }
+
+    let mut b = Bounded::<Vec<u8>>::B(Stub);
+    b.write_all(b"hello").unwrap();
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+// the field types have generic arguments and the delegated methods have generic parameters
+#[impl_enum::with_methods {
+    fn len(&self) -> usize
+    fn get<Q: ?Sized + Hash + Ord>(&self, key: &Q) -> Option<&V> where K: Borrow<Q>
+    fn contains_key<Q: ?Sized + Hash + Ord>(&self, key: &Q) -> bool where K: Borrow<Q>
+    fn insert(&mut self, key: K, value: V) -> Option<V>
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) in Extend<(K, V)>
+}]
+enum Map<K: Hash + Ord, V> {
+    Hash(HashMap<K, V>),
+    BTree { map: BTreeMap<K, V> },
+}
+
+#[test]
+fn generic_fields() {
+    for mut map in [
+        Map::Hash(HashMap::new()),
+        Map::BTree {
+            map: BTreeMap::new(),
+        },
+    ] {
+        assert_eq!(None, map.insert("a".to_string(), 1));
+        map.extend([("b".to_string(), 2)]);
+        assert_eq!(2, map.len());
+        assert_eq!(Some(&1), map.get("a"));
+        assert!(map.contains_key("b"));
+        assert!(!map.contains_key("c"));
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "with_methods")]
+
+use std::io::{self, Write};
+
+trait Encode {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()>;
+    fn encoded_len<W>(&self) -> usize
+    where
+        W: Write;
+}
+
+struct Text(String);
+struct Bytes(Vec<u8>);
+
+impl Encode for Text {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(self.0.as_bytes())
+    }
+
+    fn encoded_len<W>(&self) -> usize
+    where
+        W: Write,
+    {
+        self.0.len()
+    }
+}
+
+impl Encode for Bytes {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.0)
+    }
+
+    fn encoded_len<W>(&self) -> usize
+    where
+        W: Write,
+    {
+        self.0.len()
+    }
+}
+
+impl Bytes {
+    fn first<const N: usize>(&self) -> Vec<u8> {
+        self.0.iter().take(N).copied().collect()
+    }
+}
+
+impl Text {
+    fn first<const N: usize>(&self) -> Vec<u8> {
+        self.0.bytes().take(N).collect()
+    }
+}
+
+// `W` can't be inferred for `encoded_len`, so it's passed to the delegated call explicitly
+#[impl_enum::with_methods {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> in Encode
+    fn encoded_len<W>(&self) -> usize where W: Write in Encode
+    fn first<const N: usize>(&self) -> Vec<u8>
+}]
+enum Message {
+    Text(Text),
+    Bytes(Bytes),
+}
+
+#[test]
+fn generic_methods() {
+    let text = Message::Text(Text("text".to_string()));
+    let bytes = Message::Bytes(Bytes(vec![1, 2]));
+    let mut buf = vec![];
+    text.encode(&mut buf).unwrap();
+    bytes.encode(&mut buf).unwrap();
+    assert_eq!(b"text\x01\x02", buf.as_slice());
+    assert_eq!(4, text.encoded_len::<Vec<u8>>());
+    assert_eq!(2, bytes.encoded_len::<io::Sink>());
+    assert_eq!(b"te", text.first::<2>().as_slice());
+    assert_eq!(vec![1], bytes.first::<1>());
+}
+
+struct Small(u8);
+struct Large(u64);
+
+impl Small {
+    fn cmp_with<T>(&self, other: T) -> bool
+    where
+        T: Into<u64>,
+    {
+        u64::from(self.0) == other.into()
+    }
+
+    fn cmp_with_both<T, U>(&self, other: T, another: U) -> bool
+    where
+        T: Into<u64>,
+        U: Into<u64>,
+    {
+        self.cmp_with(other) && self.cmp_with(another)
+    }
+}
+
+impl Large {
+    fn cmp_with<T>(&self, other: T) -> bool
+    where
+        T: Into<u64>,
+    {
+        self.0 == other.into()
+    }
+
+    fn cmp_with_both<T, U>(&self, other: T, another: U) -> bool
+    where
+        T: Into<u64>,
+        U: Into<u64>,
+    {
+        self.cmp_with(other) && self.cmp_with(another)
+    }
+}
+
+// the `where` clauses are kept on the generated methods
+#[impl_enum::with_methods {
+    fn cmp_with<T>(&self, other: T) -> bool where T: Into<u64>
+    fn cmp_with_both<T, U>(&self, other: T, another: U) -> bool where T: Into<u64>, U: Into<u64>
+}]
+enum Number {
+    Small(Small),
+    Large(Large),
+}
+
+#[test]
+fn where_clause() {
+    assert!(Number::Small(Small(1)).cmp_with(1u8));
+    assert!(Number::Large(Large(2)).cmp_with(2u32));
+    assert!(!Number::Large(Large(2)).cmp_with(3u64));
+    assert!(Number::Small(Small(4)).cmp_with_both(4u16, 4u8));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "with_methods")]
+
+trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+struct Meters(u32);
+struct Feet(u32);
+
+impl Convert<u32> for Meters {
+    fn convert(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Convert<String> for Meters {
+    fn convert(&self) -> String {
+        format!("{}m", self.0)
+    }
+}
+
+impl Convert<u32> for Feet {
+    fn convert(&self) -> u32 {
+        self.0 * 3 / 10
+    }
+}
+
+impl Convert<String> for Feet {
+    fn convert(&self) -> String {
+        format!("{}ft", self.0)
+    }
+}
+
+// the trait takes the method's type parameter, which is bounded for every field type
+#[impl_enum::with_methods {
+    fn convert<T>(&self) -> T where Meters: Convert<T>, Feet: Convert<T> in Convert<T>
+}]
+enum Length {
+    Meters(Meters),
+    Feet { feet: Feet },
+}
+
+#[test]
+fn convert() {
+    let meters = Length::Meters(Meters(3));
+    assert_eq!(3, meters.convert::<u32>());
+    assert_eq!("3m", meters.convert::<String>());
+
+    let feet = Length::Feet { feet: Feet(10) };
+    let converted: u32 = feet.convert();
+    assert_eq!(3, converted);
+    assert_eq!("10ft", feet.convert::<String>());
+}
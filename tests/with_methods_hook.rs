@@ -0,0 +1,45 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    io::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static WRITES: AtomicUsize = AtomicUsize::new(0);
+static FLUSHES: AtomicUsize = AtomicUsize::new(0);
+
+macro_rules! count {
+    ("write") => {
+        WRITES.fetch_add(1, Ordering::SeqCst)
+    };
+    ("flush") => {
+        FLUSHES.fetch_add(1, Ordering::SeqCst)
+    };
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(hook = count)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>
+    #[impl_enum(hook = count)]
+    fn flush(&mut self) -> std::io::Result<()>
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()>
+}]
+enum Writer {
+    Vec(Vec<u8>),
+    Sink { sink: std::io::Sink },
+}
+
+#[test]
+fn hook() {
+    let mut writer = Writer::Vec(vec![]);
+    writer.write(b"abc").unwrap();
+    writer.flush().unwrap();
+    let mut writer = Writer::Sink {
+        sink: std::io::sink(),
+    };
+    writer.write(b"abc").unwrap();
+    // not hooked
+    writer.write_all(b"abc").unwrap();
+    assert_eq!(2, WRITES.load(Ordering::SeqCst));
+    assert_eq!(1, FLUSHES.load(Ordering::SeqCst));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "with_methods")]
+
+trait Lookup {
+    fn get<'a>(&self, key: &'a str) -> &'a str;
+}
+
+struct Prefix(usize);
+struct Suffix(usize);
+
+impl Lookup for Prefix {
+    fn get<'a>(&self, key: &'a str) -> &'a str {
+        &key[..self.0]
+    }
+}
+
+impl Lookup for Suffix {
+    fn get<'a>(&self, key: &'a str) -> &'a str {
+        &key[key.len() - self.0..]
+    }
+}
+
+#[impl_enum::with_methods {
+    fn get<'a>(&self, key: &'a str) -> &'a str
+}]
+enum Slicer {
+    Prefix(Prefix),
+    Suffix { suffix: Suffix },
+}
+
+#[test]
+fn argument_lifetime() {
+    let key = "prefix-suffix".to_string();
+    // the result outlives the enum it was returned from
+    let prefix = {
+        let slicer = Slicer::Prefix(Prefix(6));
+        slicer.get(&key)
+    };
+    let suffix = {
+        let slicer = Slicer::Suffix { suffix: Suffix(6) };
+        slicer.get(&key)
+    };
+    assert_eq!("prefix", prefix);
+    assert_eq!("suffix", suffix);
+}
+
+struct Lease<'a>(&'a mut Vec<u8>);
+
+trait Lessor {
+    fn lease<'a>(&'a mut self) -> Lease<'a>;
+}
+
+struct Pool(Vec<u8>);
+
+impl Lessor for Pool {
+    fn lease<'a>(&'a mut self) -> Lease<'a> {
+        Lease(&mut self.0)
+    }
+}
+
+impl Lessor for Vec<u8> {
+    fn lease<'a>(&'a mut self) -> Lease<'a> {
+        Lease(self)
+    }
+}
+
+#[impl_enum::with_methods {
+    fn lease<'a>(&'a mut self) -> Lease<'a>
+}]
+enum Lessors {
+    Pool(Pool),
+    Vec { vec: Vec<u8> },
+}
+
+#[test]
+fn receiver_lifetime() {
+    let mut pool = Lessors::Pool(Pool(vec![]));
+    pool.lease().0.push(1);
+    let mut vec = Lessors::Vec { vec: vec![] };
+    vec.lease().0.push(2);
+    match (pool, vec) {
+        (Lessors::Pool(Pool(pool)), Lessors::Vec { vec }) => {
+            assert_eq!(vec![1], pool);
+            assert_eq!(vec![2], vec);
+        }
+        _ => panic!(),
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "with_methods")]
+#![allow(dead_code)]
+
+#[derive(Debug, PartialEq)]
+struct Buffer(Vec<u8>);
+
+#[derive(Debug, PartialEq)]
+struct Report {
+    len: usize,
+    label: &'static str,
+}
+
+impl Buffer {
+    fn report(&self, label: &'static str) -> Report {
+        Report {
+            len: self.0.len(),
+            label,
+        }
+    }
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(parts)]
+    fn report(self, label: &'static str) -> (Buffer, Report)
+}]
+enum Stage {
+    Open(Buffer),
+    Closed { buffer: Buffer, reason: String },
+}
+
+#[test]
+fn parts() {
+    let open = Stage::Open(Buffer(vec![1, 2]));
+    let (buffer, report) = open.report("open");
+    assert_eq!(Buffer(vec![1, 2]), buffer);
+    assert_eq!(
+        Report {
+            len: 2,
+            label: "open"
+        },
+        report
+    );
+
+    let closed = Stage::Closed {
+        buffer: Buffer(vec![]),
+        reason: "done".to_string(),
+    };
+    let (buffer, report) = closed.report("closed");
+    assert_eq!(Buffer(vec![]), buffer);
+    assert_eq!(0, report.len);
+    assert_eq!("closed", report.label);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "with_methods")]
+
+#[derive(Debug, PartialEq)]
+struct Builder {
+    finalized: bool,
+}
+
+impl Builder {
+    fn finalize(self) -> Self {
+        Self { finalized: true }
+    }
+}
+
+#[impl_enum::with_methods {
+    fn finalize(self) -> Self
+}]
+#[derive(Debug, PartialEq)]
+enum Partial {
+    Builder(Builder, &'static str),
+    #[impl_enum(passthrough)]
+    Fixed(String),
+    #[impl_enum(passthrough)]
+    Empty,
+}
+
+#[test]
+fn passthrough() {
+    let builder = Partial::Builder(Builder { finalized: false }, "tag");
+    assert_eq!(
+        Partial::Builder(Builder { finalized: true }, "tag"),
+        builder.finalize()
+    );
+
+    let fixed = Partial::Fixed("fixed".to_string());
+    assert_eq!(Partial::Fixed("fixed".to_string()), fixed.finalize());
+
+    assert_eq!(Partial::Empty, Partial::Empty.finalize());
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "with_methods")]
+
+trait Grid {
+    fn set(&mut self, position: (usize, usize), value: u8);
+}
+
+struct Dense(Vec<Vec<u8>>);
+struct Sparse(Vec<((usize, usize), u8)>);
+
+impl Dense {
+    fn get(&self, (x, y): (usize, usize)) -> Option<u8> {
+        self.0.get(y)?.get(x).copied()
+    }
+}
+
+impl Grid for Dense {
+    fn set(&mut self, (x, y): (usize, usize), value: u8) {
+        self.0[y][x] = value;
+    }
+}
+
+impl Sparse {
+    fn get(&self, position: (usize, usize)) -> Option<u8> {
+        self.0.iter().find(|(p, _)| *p == position).map(|(_, v)| *v)
+    }
+}
+
+impl Grid for Sparse {
+    fn set(&mut self, position: (usize, usize), value: u8) {
+        self.0.push((position, value));
+    }
+}
+
+// the patterns are not valid expressions for the delegated calls
+#[impl_enum::with_methods(trait_impls, ref_mut_impls, {
+    fn get(&self, (x, y): (usize, usize)) -> Option<u8>
+    fn set(&mut self, (x, y): (usize, usize), value: u8) in Grid
+})]
+enum AnyGrid {
+    Dense(Dense),
+    Sparse(Sparse),
+}
+
+fn fill(mut grid: impl Grid) {
+    grid.set((0, 0), 7);
+}
+
+#[test]
+fn patterns() {
+    let mut dense = AnyGrid::Dense(Dense(vec![vec![0, 1], vec![2, 3]]));
+    assert_eq!(Some(2), dense.get((0, 1)));
+    dense.set((1, 1), 4);
+    assert_eq!(Some(4), dense.get((1, 1)));
+    let mut sparse = AnyGrid::Sparse(Sparse(vec![]));
+    assert_eq!(None, sparse.get((0, 0)));
+    fill(&mut sparse);
+    assert_eq!(Some(7), sparse.get((0, 0)));
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    marker::PhantomPinned,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+};
+
+trait Stream {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>>;
+}
+
+struct Countdown(u32);
+
+impl Stream for Countdown {
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.0 == 0 {
+            return Poll::Ready(None);
+        }
+        self.0 -= 1;
+        Poll::Ready(Some(self.0))
+    }
+}
+
+// not `Unpin`, so it can only be polled through a pin
+struct Once {
+    value: Option<u32>,
+    _pinned: PhantomPinned,
+}
+
+impl Stream for Once {
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        // SAFETY: the value is not pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        Poll::Ready(this.value.take())
+    }
+}
+
+#[impl_enum::with_methods(trait_impls, {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> in Stream
+})]
+enum AnyStream {
+    Countdown(Countdown),
+    Once { once: Once },
+}
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn collect(mut stream: Pin<Box<dyn Stream>>) -> Vec<u32> {
+    let waker = Arc::new(Noop).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut items = vec![];
+    while let Poll::Ready(Some(item)) = stream.as_mut().poll_next(&mut cx) {
+        items.push(item);
+    }
+    items
+}
+
+#[test]
+fn pin() {
+    let countdown = Box::pin(AnyStream::Countdown(Countdown(3)));
+    assert_eq!(vec![2, 1, 0], collect(countdown));
+    let once = Box::pin(AnyStream::Once {
+        once: Once {
+            value: Some(5),
+            _pinned: PhantomPinned,
+        },
+    });
+    assert_eq!(vec![5], collect(once));
+}
+
+#[impl_enum::with_methods(trait_impls, {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> in Stream
+})]
+enum Generic<'a, S: Stream> {
+    Borrowed(&'a mut Countdown),
+    Other { stream: S },
+}
+
+impl Stream for &mut Countdown {
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        Pin::new(&mut **self).poll_next(cx)
+    }
+}
+
+#[test]
+fn unpin_fields() {
+    // the enum is `Unpin` when the fields are, so it can be pinned on the stack
+    let waker = Arc::new(Noop).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut countdown = Countdown(2);
+    let mut stream = Generic::<Countdown>::Borrowed(&mut countdown);
+    assert_eq!(
+        Poll::Ready(Some(1)),
+        Pin::new(&mut stream).poll_next(&mut cx)
+    );
+    let mut stream: Generic<Countdown> = Generic::Other {
+        stream: Countdown(1),
+    };
+    assert_eq!(
+        Poll::Ready(Some(0)),
+        Pin::new(&mut stream).poll_next(&mut cx)
+    );
+    assert_eq!(1, countdown.0);
+}
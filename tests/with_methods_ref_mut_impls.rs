@@ -0,0 +1,87 @@
+#![cfg(feature = "with_methods")]
+
+use std::io::{self, Write};
+
+trait Sink {
+    fn put(&mut self, byte: u8);
+    fn put_all(&mut self, bytes: &[u8]) -> usize;
+}
+
+impl Sink for Vec<u8> {
+    fn put(&mut self, byte: u8) {
+        self.push(byte);
+    }
+    fn put_all(&mut self, bytes: &[u8]) -> usize {
+        self.extend_from_slice(bytes);
+        bytes.len()
+    }
+}
+
+impl Sink for String {
+    fn put(&mut self, byte: u8) {
+        self.push(char::from(byte));
+    }
+    fn put_all(&mut self, bytes: &[u8]) -> usize {
+        bytes.iter().for_each(|byte| self.put(*byte));
+        bytes.len()
+    }
+}
+
+#[impl_enum::with_methods(trait_impls, ref_mut_impls, {
+    fn put(&mut self, byte: u8) in Sink
+    fn put_all(&mut self, bytes: &[u8]) -> usize in Sink
+})]
+#[derive(Debug, PartialEq)]
+enum AnySink {
+    Bytes(Vec<u8>),
+    Text { text: String },
+}
+
+fn fill<S: Sink>(mut sink: S) {
+    sink.put(b'a');
+    assert_eq!(2, sink.put_all(b"bc"));
+}
+
+#[test]
+fn ref_mut() {
+    let mut bytes = AnySink::Bytes(vec![]);
+    fill(&mut bytes);
+    assert_eq!(AnySink::Bytes(b"abc".to_vec()), bytes);
+
+    let mut text = AnySink::Text {
+        text: String::new(),
+    };
+    fill(&mut text);
+    fill(&mut text);
+    assert_eq!(
+        AnySink::Text {
+            text: "abcabc".to_string()
+        },
+        text
+    );
+}
+
+// std already implements Write for mutable references to writers
+#[impl_enum::with_methods(trait_impls, {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> in Write
+    fn flush(&mut self) -> io::Result<()> in Write
+})]
+enum Writer {
+    Vec(Vec<u8>),
+    Sink(io::Sink),
+}
+
+fn write_to<W: Write>(mut writer: W) {
+    writer.write_all(b"abc").unwrap();
+}
+
+#[test]
+fn std_write() {
+    let mut writer = Writer::Vec(vec![]);
+    write_to(&mut writer);
+    write_to(Writer::Sink(io::sink()));
+    match writer {
+        Writer::Vec(vec) => assert_eq!(b"abc", vec.as_slice()),
+        Writer::Sink(_) => panic!(),
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "with_methods")]
+
+use std::io::Write;
+
+trait Named {
+    fn name(&self) -> String;
+}
+
+impl Named for Vec<u8> {
+    fn name(&self) -> String {
+        "vec".to_string()
+    }
+}
+
+impl Named for String {
+    fn name(&self) -> String {
+        "string".to_string()
+    }
+}
+
+#[impl_enum::with_methods(check, {
+    pub fn inner_len(&self) -> usize = len
+    fn inner_name(&self) -> String = name in Named
+    fn is_empty(&self) -> bool
+})]
+enum Buffer {
+    Vec(Vec<u8>),
+    String { string: String },
+}
+
+#[impl_enum::with_methods {
+    fn write_bytes(&mut self, buf: &[u8]) -> std::io::Result<()> = write_all
+    fn r#flush(&mut self) -> std::io::Result<()> = r#flush
+}]
+enum Writer {
+    Vec(Vec<u8>),
+}
+
+#[test]
+fn rename() {
+    let vec = Buffer::Vec(vec![1, 2]);
+    assert_eq!(2, vec.inner_len());
+    assert_eq!("vec", vec.inner_name());
+    assert!(!vec.is_empty());
+    let string = Buffer::String {
+        string: "abc".to_string(),
+    };
+    assert_eq!(3, string.inner_len());
+    assert_eq!("string", string.inner_name());
+
+    let mut writer = Writer::Vec(vec![]);
+    writer.write_bytes(b"written").unwrap();
+    writer.flush().unwrap();
+    let Writer::Vec(vec) = writer;
+    assert_eq!(b"written", vec.as_slice());
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "with_methods")]
+
+#[derive(Debug, PartialEq)]
+struct Http {
+    verbose: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct Ftp {
+    verbose: bool,
+}
+
+impl Http {
+    fn with_verbose(self, verbose: bool) -> Self {
+        Http { verbose }
+    }
+}
+
+impl Ftp {
+    fn with_verbose(self, verbose: bool) -> Self {
+        Ftp { verbose }
+    }
+}
+
+// the result is wrapped back into the variant along with the other fields
+#[impl_enum::with_methods {
+    fn with_verbose(self, verbose: bool) -> Self
+}]
+#[derive(Debug, PartialEq)]
+enum Client {
+    Http(Http, u16),
+    Ftp(Ftp),
+}
+
+// the return type may also name the enum
+#[impl_enum::with_methods {
+    fn with_verbose(self, verbose: bool) -> Named
+}]
+#[derive(Debug, PartialEq)]
+enum Named {
+    Http(Http),
+    Ftp { ftp: Ftp },
+}
+
+#[impl_enum::with_methods {
+    fn clone(&self) -> Generic<T>
+}]
+#[derive(Debug, PartialEq)]
+enum Generic<T: Clone> {
+    Vec(Vec<T>),
+    Option(Option<T>),
+}
+
+#[test]
+fn rewrap() {
+    let http = Client::Http(Http { verbose: false }, 80);
+    assert_eq!(
+        Client::Http(Http { verbose: true }, 80),
+        http.with_verbose(true)
+    );
+    let ftp = Named::Ftp {
+        ftp: Ftp { verbose: true },
+    };
+    assert_eq!(
+        Named::Ftp {
+            ftp: Ftp { verbose: false }
+        },
+        ftp.with_verbose(false)
+    );
+    let named = Named::Http(Http { verbose: false });
+    assert_eq!(
+        Named::Http(Http { verbose: true }),
+        named.with_verbose(true)
+    );
+
+    let generic = Generic::Vec(vec![1]);
+    assert_eq!(Generic::Vec(vec![1]), generic.clone());
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "with_methods")]
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool,
+    fn capacity(&self) -> usize
+    fn clear(&mut self);
+    fn starts_with_char<C>(&self, c: C) -> bool where C: Into<char> in StartsWith;
+    fn first_char(&self) -> Option<char> in StartsWith,
+}]
+enum Text {
+    String(String),
+    Chars(Vec<char>),
+}
+
+trait StartsWith {
+    fn first_char(&self) -> Option<char>;
+
+    fn starts_with_char<C>(&self, c: C) -> bool
+    where
+        C: Into<char>,
+    {
+        self.first_char() == Some(c.into())
+    }
+}
+
+impl StartsWith for String {
+    fn first_char(&self) -> Option<char> {
+        self.chars().next()
+    }
+}
+
+impl StartsWith for Vec<char> {
+    fn first_char(&self) -> Option<char> {
+        self.first().copied()
+    }
+}
+
+#[test]
+fn separators() {
+    let mut string = Text::String("ab".to_string());
+    assert_eq!(2, string.len());
+    assert!(!string.is_empty());
+    assert!(string.capacity() >= 2);
+    assert!(string.starts_with_char('a'));
+    string.clear();
+    assert!(string.is_empty());
+    let chars = Text::Chars(vec!['c']);
+    assert_eq!(Some('c'), chars.first_char());
+    assert!(chars.starts_with_char(b'c'));
+}
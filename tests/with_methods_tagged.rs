@@ -0,0 +1,36 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    collections::VecDeque,
+    io::{self, Read},
+};
+
+#[impl_enum::with_methods {
+    #[impl_enum(tagged)]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+}]
+enum Source {
+    Bytes(&'static [u8]),
+    Empty { empty: io::Empty },
+    Deque(VecDeque<u8>),
+}
+
+#[test]
+fn tagged() {
+    let mut buf = [0; 4];
+
+    let mut bytes = Source::Bytes(b"abc");
+    let (tag, read) = bytes.read(&mut buf);
+    assert_eq!(0, tag);
+    assert_eq!(3, read.unwrap());
+
+    let mut empty = Source::Empty { empty: io::empty() };
+    let (tag, read) = empty.read(&mut buf);
+    assert_eq!(1, tag);
+    assert_eq!(0, read.unwrap());
+
+    let mut deque = Source::Deque(VecDeque::from(vec![1, 2]));
+    let (tag, read) = deque.read(&mut buf);
+    assert_eq!(2, tag);
+    assert_eq!(2, read.unwrap());
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "with_methods", feature = "nightly"))]
+#![feature(type_alias_impl_trait)]
+
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+};
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(Noop).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+struct A(Vec<u8>);
+struct B;
+
+impl A {
+    async fn send(&mut self, msg: &[u8]) -> usize {
+        self.0.extend_from_slice(msg);
+        self.0.len()
+    }
+}
+
+impl B {
+    async fn send(&mut self, msg: &[u8]) -> usize {
+        msg.len() * 2
+    }
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(tait)]
+    pub async fn send(&mut self, msg: &[u8]) -> usize
+}]
+enum Connection {
+    A(A),
+    B { b: B },
+}
+
+struct Pending<'a> {
+    future: ConnectionSendFuture<'a>,
+}
+
+#[test]
+fn tait() {
+    let mut a = Connection::A(A(vec![1]));
+    let pending = Pending {
+        future: a.send(&[2, 3]),
+    };
+    assert_eq!(3, block_on(pending.future));
+
+    let mut b = Connection::B { b: B };
+    assert_eq!(4, block_on(b.send(&[2, 3])));
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "with_methods", feature = "as_dyn"))]
+
+use std::{iter::Rev, ops::Range, vec::IntoIter};
+
+// the provided methods of the trait are called through the trait object
+#[impl_enum::with_methods {
+    #[impl_enum(through = as_dyn_iterator_mut)]
+    pub fn nth(&mut self, n: usize) -> Option<u8>
+    #[impl_enum(through = as_dyn_iterator_mut)]
+    pub fn size_hint(&mut self) -> (usize, Option<usize>)
+    #[impl_enum(through = as_dyn_iterator)]
+    pub fn len_hint(&self) -> (usize, Option<usize>)
+}]
+#[impl_enum::as_dyn(Iterator<Item = u8>)]
+enum Bytes {
+    Range(Range<u8>),
+    Rev { rev: Rev<Range<u8>> },
+    Vec(IntoIter<u8>),
+}
+
+trait LenHint {
+    fn len_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<I: Iterator + ?Sized> LenHint for I {
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        self.size_hint()
+    }
+}
+
+#[test]
+fn nth() {
+    let mut range = Bytes::Range(0..10);
+    assert_eq!(Some(2), range.nth(2));
+    assert_eq!(Some(3), range.nth(0));
+    assert_eq!((6, Some(6)), range.size_hint());
+
+    let mut rev = Bytes::Rev { rev: (0..10).rev() };
+    assert_eq!(Some(7), rev.nth(2));
+
+    let mut vec = Bytes::Vec(vec![4, 5].into_iter());
+    assert_eq!(Some(5), vec.nth(1));
+    assert_eq!(None, vec.nth(0));
+    assert_eq!((0, Some(0)), vec.len_hint());
+}
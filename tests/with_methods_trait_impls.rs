@@ -0,0 +1,63 @@
+#![cfg(feature = "with_methods")]
+
+use std::{
+    collections::VecDeque,
+    io::{self, Cursor, Read, Write},
+};
+
+#[impl_enum::with_methods(trait_impls, {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> in Read
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> in Write
+    fn flush(&mut self) -> io::Result<()> in Write
+    pub fn size(&self) -> usize
+})]
+enum Pipe {
+    Deque(VecDeque<u8>),
+    Cursor { cursor: Cursor<Vec<u8>> },
+}
+
+// methods without `in Trait` are kept in the inherent impl
+trait Size {
+    fn size(&self) -> usize;
+}
+
+impl Size for VecDeque<u8> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Size for Cursor<Vec<u8>> {
+    fn size(&self) -> usize {
+        self.get_ref().len()
+    }
+}
+
+fn write_flush(write: &mut dyn Write, data: &[u8]) {
+    write.write_all(data).unwrap();
+    write.flush().unwrap();
+}
+
+fn read_to_end(read: &mut dyn Read) -> Vec<u8> {
+    let mut buf = vec![];
+    read.read_to_end(&mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn trait_impls() {
+    let mut deque = Pipe::Deque(VecDeque::new());
+    write_flush(&mut deque, b"abc");
+    assert_eq!(3, deque.size());
+    assert_eq!(b"abc", read_to_end(&mut deque).as_slice());
+
+    let mut cursor = Pipe::Cursor {
+        cursor: Cursor::new(b"xyz".to_vec()),
+    };
+    assert_eq!(b"xyz", read_to_end(&mut cursor).as_slice());
+    write_flush(&mut cursor, b"w");
+    match cursor {
+        Pipe::Cursor { cursor } => assert_eq!(b"xyzw", cursor.get_ref().as_slice()),
+        Pipe::Deque(_) => panic!(),
+    }
+}
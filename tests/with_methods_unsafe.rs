@@ -0,0 +1,40 @@
+#![cfg(feature = "with_methods")]
+#![deny(unused_unsafe)]
+
+// the slice methods are reached through auto-deref with method call syntax
+#[impl_enum::with_methods {
+    unsafe fn get_unchecked(&self, i: usize) -> &u8
+}]
+enum Bytes {
+    #[impl_enum(access = |field| field)]
+    Vec(Vec<u8>),
+    #[impl_enum(access = |field| field)]
+    Boxed(Box<[u8]>),
+}
+
+struct Checked(Vec<u8>);
+
+impl Checked {
+    unsafe fn first_unchecked(&self) -> u8 {
+        *self.0.get_unchecked(0)
+    }
+}
+
+#[impl_enum::with_methods {
+    unsafe fn first_unchecked(&self) -> u8
+}]
+enum Single {
+    Checked(Checked),
+}
+
+#[test]
+fn unsafe_fn() {
+    let vec = Bytes::Vec(vec![1, 2]);
+    let boxed = Bytes::Boxed(vec![3, 4].into_boxed_slice());
+    let single = Single::Checked(Checked(vec![5]));
+    unsafe {
+        assert_eq!(2, *vec.get_unchecked(1));
+        assert_eq!(3, *boxed.get_unchecked(0));
+        assert_eq!(5, single.first_unchecked());
+    }
+}
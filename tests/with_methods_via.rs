@@ -0,0 +1,44 @@
+#![cfg(feature = "with_methods")]
+
+trait Bytes {
+    fn len(&self) -> usize;
+}
+
+trait Chars {
+    fn len(&self) -> usize;
+}
+
+struct Text(String);
+
+impl Bytes for Text {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Chars for Text {
+    fn len(&self) -> usize {
+        self.0.chars().count()
+    }
+}
+
+// `<Text>::len` would be ambiguous
+#[impl_enum::with_methods {
+    fn len(&self) -> usize via Chars
+}]
+enum CharCount {
+    Text(Text),
+}
+
+#[impl_enum::with_methods {
+    fn len(&self) -> usize in Bytes
+}]
+enum ByteCount {
+    Text(Text),
+}
+
+#[test]
+fn via() {
+    assert_eq!(1, CharCount::Text(Text("ä".to_string())).len());
+    assert_eq!(2, ByteCount::Text(Text("ä".to_string())).len());
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "with_methods")]
+
+mod outer {
+    pub mod inner {
+        #[impl_enum::with_methods {
+            pub(in crate::outer) fn len(&self) -> usize
+            pub(super) fn is_empty(&self) -> bool
+            pub(crate) fn capacity(&self) -> usize
+        }]
+        pub enum Buffer {
+            Vec(Vec<u8>),
+            String { string: String },
+        }
+    }
+
+    // the restricted methods are visible in the outer module
+    pub fn len(buffer: &inner::Buffer) -> usize {
+        buffer.len()
+    }
+
+    pub fn is_empty(buffer: &inner::Buffer) -> bool {
+        buffer.is_empty()
+    }
+}
+
+#[test]
+fn restricted_visibility() {
+    let buffer = outer::inner::Buffer::Vec(vec![1, 2]);
+    assert_eq!(2, outer::len(&buffer));
+    assert!(!outer::is_empty(&buffer));
+    assert!(buffer.capacity() >= 2);
+
+    let buffer = outer::inner::Buffer::String {
+        string: String::new(),
+    };
+    assert_eq!(0, outer::len(&buffer));
+    assert!(outer::is_empty(&buffer));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "with_methods")]
+
+use std::{fmt::Display, rc::Rc, sync::Arc};
+
+struct Number(i32);
+struct Text(String);
+
+impl Number {
+    fn label(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Text {
+    fn label(&self) -> String {
+        self.0.clone()
+    }
+}
+
+// the delegated methods return different types that are unified as trait objects
+#[impl_enum::with_methods {
+    #[impl_enum(wrap)]
+    fn label(&self) -> Box<dyn Display>
+}]
+enum Boxed {
+    Number(Number),
+    Text { text: Text },
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(wrap)]
+    fn label(&self) -> Rc<dyn Display>
+}]
+enum Shared {
+    Number(Number),
+    Text { text: Text },
+}
+
+#[impl_enum::with_methods {
+    #[impl_enum(wrap)]
+    fn label(&self) -> Arc<dyn Display + Send + Sync>
+}]
+enum Atomic {
+    Number(Number),
+    Text { text: Text },
+}
+
+#[test]
+fn boxed() {
+    assert_eq!("1", Boxed::Number(Number(1)).label().to_string());
+    let text = Boxed::Text {
+        text: Text("text".to_string()),
+    };
+    assert_eq!("text", text.label().to_string());
+}
+
+#[test]
+fn rc() {
+    let label = Shared::Number(Number(2)).label();
+    let clone = Rc::clone(&label);
+    assert_eq!("2", clone.to_string());
+    let text = Shared::Text {
+        text: Text("text".to_string()),
+    };
+    assert_eq!("text", text.label().to_string());
+}
+
+#[test]
+fn arc() {
+    let label = Atomic::Number(Number(3)).label();
+    let handle = std::thread::spawn(move || label.to_string());
+    assert_eq!("3", handle.join().unwrap());
+    let text = Atomic::Text {
+        text: Text("text".to_string()),
+    };
+    assert_eq!("text", text.label().to_string());
+}